@@ -9,8 +9,17 @@ mod toast;
 mod toaster;
 
 pub use crate::{
-    toast::{ToastBuilder, ToastLevel, ToastPosition},
-    toaster::{expect_toaster, provide_toaster, provide_toaster_with_defaults, Toaster},
+    toast::{
+        CloseReason, DismissMode, PromiseMessages, ToastAnimation, ToastBuilder, ToastHandle,
+        ToastId, ToastLevel, ToastLive, ToastLiveLevels, ToastPosition, ToasterClasses,
+        ToasterHotkey, ToasterMode,
+    },
+    toaster::{
+        expect_named_toaster, expect_toaster, provide_named_toaster,
+        provide_named_toaster_with_defaults, provide_toaster, provide_toaster_with_defaults,
+        theme::{ToastColors, ToasterTheme},
+        Toaster, LEPTOASTER_CSS,
+    },
 };
 
 pub fn demo() {
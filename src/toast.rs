@@ -7,124 +7,828 @@
 
 mod builder;
 mod data;
+mod handle;
+mod icon;
+mod promise;
+mod snapshot;
+mod style;
+mod timer;
 
-use crate::toaster::expect_toaster;
+use std::collections::HashMap;
+
+use crate::{
+    toast::{icon::default_icon, timer::ExpiryTimer},
+    toaster::{
+        context::{focus_element, ToasterContext},
+        expect_toaster,
+        stack::{ToastSize, ToastStack},
+    },
+};
 use gloo_timers::future::TimeoutFuture;
 use leptos::*;
+use wasm_bindgen::JsCast;
+
+/// The distance in pixels a toast must be dragged before it is dismissed when released.
+const SWIPE_THRESHOLD: f64 = 60.0;
+
+/// The velocity in pixels per millisecond above which a shorter drag dismisses the toast.
+const SWIPE_VELOCITY_THRESHOLD: f64 = 0.5;
+
+/// The distance in pixels a pointer can move before it is treated as a drag rather than a click.
+const DRAG_SLOP: f64 = 5.0;
+
+/// The duration in milliseconds of the transition back to rest after a short drag.
+const SNAP_BACK_DURATION: u32 = 200;
+
+/// The elements within a toast which keep their own pointer interactions rather than
+/// starting a drag.
+const INTERACTIVE_SELECTOR: &str = "a, button, input, select, textarea, [contenteditable]";
 
-pub use crate::toast::data::{ToastData, ToastId, ToastLevel, ToastPosition};
+pub use crate::toast::data::{
+    CloseReason, DismissMode, ToastAction, ToastAnimation, ToastData, ToastIcon, ToastId,
+    ToastLevel, ToastLive, ToastLiveLevels, ToastPosition, ToasterClasses, ToasterHotkey,
+    ToasterMode,
+};
 
-/// A toast element with the supplied alert style.
+pub(crate) use crate::toast::{
+    snapshot::ToastSnapshot,
+    style::{create_style_effect, style_attribute, InlineStyles},
+};
+
+/// A toast element with the supplied alert style. Unstyled toasts only keep the inline
+/// styles which drive their animations, and are otherwise styled with the supplied
+/// classes. Under class styles, the toasts are styled with the stylesheet's classes.
 #[component]
-pub fn Toast(toast: ToastData) -> impl IntoView {
-    let animation_duration = 200;
+pub fn Toast(
+    toast: ToastData,
+    #[prop(optional)] default_animation: ToastAnimation,
+    #[prop(optional, into)] reduced_motion: MaybeSignal<bool>,
+    #[prop(optional)] unstyled: bool,
+    #[prop(optional)] class_styles: bool,
+    #[prop(optional)] classes: ToasterClasses,
+    #[prop(optional, into)] stack: MaybeSignal<Option<ToastStack>>,
+    #[prop(optional)] sizes: Option<RwSignal<HashMap<ToastId, ToastSize>>>,
+    #[prop(optional, into)] container_paused: MaybeSignal<bool>,
+) -> impl IntoView {
+    let toaster = store_value(expect_toaster());
+    let handle = ToastHandle::new(&toast, toaster.with_value(|toaster| toaster.pending));
+    let reduced_motion = reduced_motion.get_untracked();
+
+    let styled = move |value: &'static str| (!unstyled).then_some(value);
+
+    // server rendered toasts have already been shown, so they do not animate in again
+    let hydrating = leptos_dom::HydrationCtx::is_hydrating();
+    let classes = store_value(classes);
+
+    // the toast's own animation takes precedence over the default animation, and is
+    // replaced with a fade under reduced motion
+    let animation = match toast.animation.clone().unwrap_or(default_animation) {
+        ToastAnimation::None => ToastAnimation::None,
+        _ if reduced_motion => ToastAnimation::Fade,
+        animation => animation,
+    };
+    let animation_duration = get_animation_duration(&animation);
+
+    let enter_animation_name = get_enter_animation_name(&animation, &toast.position);
+    let exit_animation_name = get_exit_animation_name(&animation, &toast.position);
 
-    let slide_in_animation_name = get_slide_in_animation_name(&toast.position);
-    let slide_out_animation_name = get_slide_out_animation_name(&toast.position);
+    let (animation_name, set_animation_name) = create_signal(match hydrating {
+        true => "none".into(),
+        false => enter_animation_name,
+    });
+    let (animation_duration, set_animation_duration) = create_signal(animation_duration);
 
-    let (animation_name, set_animation_name) = create_signal(slide_in_animation_name);
+    let colors = create_memo(move |_| get_colors(&toast.level.get()));
 
-    let (background_color, border_color, text_color) = get_colors(&toast.level);
-    let (initial_left, initial_right) = get_initial_positions(&toast.position);
+    let (offsets, set_offsets) = create_signal(match animation {
+        ToastAnimation::Slide if !hydrating => get_initial_positions(&toast.position),
+        _ => ("auto", "auto"),
+    });
 
-    create_resource(
-        || (),
-        move |()| async move {
-            let Some(expiry) = toast.expiry else {
+    let swipe_direction = get_swipe_direction(&toast.position);
+    let swipeable = toast.dismiss_mode.click();
+
+    let drag_start = store_value(None::<(f64, f64, f64)>);
+    let drag_moved = store_value(false);
+    let swiped = store_value(false);
+    let (dragging, set_dragging) = create_signal(false);
+    let (drag_offset, set_drag_offset) = create_signal(0.0);
+    let (drag_transition, set_drag_transition) = create_signal(SNAP_BACK_DURATION);
+
+    let (hovered, set_hovered) = create_signal(false);
+    let (focused, set_focused) = create_signal(false);
+    let (hidden, set_hidden) = create_signal(false);
+
+    // pausable toasts pause while their container is hovered or focused, as well as while
+    // they are hovered or focused themselves
+    let paused = create_memo(move |_| {
+        toast.pausable
+            && (hovered.get()
+                || focused.get()
+                || hidden.get()
+                || dragging.get()
+                || container_paused.get())
+    });
+
+    let visibility_handle = window_event_listener_untyped("visibilitychange", move |_| {
+        set_hidden.set(document().hidden());
+    });
+
+    on_cleanup(move || visibility_handle.remove());
+
+    let timer = ExpiryTimer::new();
+
+    create_effect(move |_| {
+        set_hidden.set(document().hidden());
+    });
+
+    create_effect(move |_| match paused.get() {
+        true => timer.pause(),
+        false => timer.resume(),
+    });
+
+    create_local_resource(
+        // the restart counter restarts the timer even if the expiry is unchanged
+        move || (toast.expiry.get(), toast.restart.get()),
+        move |(expiry, _)| async move {
+            let Some(expiry) = expiry else {
+                timer.cancel();
                 return;
             };
 
-            TimeoutFuture::new(expiry).await;
+            let run = timer.start(expiry);
 
-            if toast.clear_signal.get_untracked() {
-                return;
+            if timer.wait(run).await {
+                handle.close(CloseReason::Expired);
             }
-
-            toast.clear_signal.set(true);
         },
     );
 
-    create_resource(
+    let on_close = toast.on_close;
+
+    create_local_resource(
         move || toast.clear_signal.get(),
-        move |clear| async move {
-            if clear {
-                set_animation_name.set(slide_out_animation_name);
-                TimeoutFuture::new(animation_duration).await;
-                expect_toaster().remove(toast.id);
+        move |clear| {
+            let exit_animation_name = match swiped.get_value() {
+                true => "leptoaster-fade-out".into(),
+                false => exit_animation_name.clone(),
+            };
+
+            if let (true, Some(on_close)) = (clear, &on_close) {
+                let reason = toast.close_reason.get_value();
+                on_close.call(reason.unwrap_or(CloseReason::Dismissed));
+            }
+
+            async move {
+                if clear {
+                    set_animation_name.set(exit_animation_name);
+                    TimeoutFuture::new(animation_duration.get_untracked()).await;
+
+                    // the toast is no longer rendered if it was removed, or its toaster
+                    // unmounted, while it was animating out
+                    _ = toaster.try_with_value(|toaster| toaster.remove(toast.id));
+                }
             }
         },
     );
 
+    if let Some(on_show) = toast.on_show {
+        create_effect(move |_| untrack(|| on_show.call(())));
+    }
+
+    let on_click = toast.on_click;
+
     let handle_click = move |_| {
-        if !toast.dismissable {
+        if drag_moved.get_value() {
+            drag_moved.set_value(false);
+            return;
+        }
+
+        if let Some(on_click) = &on_click {
+            on_click.call(());
+        }
+
+        if !toast.dismiss_mode.click() {
             return;
         }
 
-        toast.clear_signal.set(true);
+        handle.close(CloseReason::Click);
     };
 
+    let action_button = |action: ToastAction, bordered: bool| {
+        let handle_action_click = move |ev: ev::MouseEvent| {
+            ev.stop_propagation();
+            action.callback.call(());
+
+            if toast.dismiss_on_action {
+                handle.close(CloseReason::Action);
+            }
+        };
+
+        view! {
+            <button
+                type="button"
+                class=classes.with_value(|classes| join_classes(&[
+                    "leptoaster-action",
+                    match bordered {
+                        true => "leptoaster-action-primary",
+                        false => "",
+                    },
+                    &classes.action,
+                ]))
+                {..style_attribute(!class_styles, move || {
+                    InlineStyles::new()
+                        .with("color", styled(colors.get().2))
+                        .with("background-color", styled("transparent"))
+                        .with("border", styled("1px solid"))
+                        .with(
+                            "border-color",
+                            styled(match bordered {
+                                true => colors.get().2,
+                                false => "transparent",
+                            }),
+                        )
+                        .with("border-radius", styled("4px"))
+                        .with("padding", styled("0 8px"))
+                        .with("font-size", styled("var(--leptoaster-font-size)"))
+                        .with("line-height", styled("var(--leptoaster-line-height)"))
+                        .with("font-family", styled("var(--leptoaster-font-family)"))
+                        .with("font-weight", styled("var(--leptoaster-font-weight)"))
+                        .with("cursor", styled("pointer"))
+                        .with("white-space", styled("nowrap"))
+                })}
+                on:click=handle_action_click
+            >
+                {action.label}
+            </button>
+        }
+    };
+
+    let has_actions = toast.action.is_some() || toast.cancel.is_some();
+
+    let handle_close_click = move |ev: ev::MouseEvent| {
+        ev.stop_propagation();
+        handle.close(CloseReason::CloseButton);
+    };
+
+    let handle_keydown = move |ev: ev::KeyboardEvent| {
+        let Some(current) = ev.current_target() else {
+            return;
+        };
+
+        if ev.target().as_ref() != Some(&current) {
+            return;
+        }
+
+        let element = current.unchecked_into::<web_sys::Element>();
+
+        match ev.key().as_str() {
+            "ArrowDown" | "ArrowUp" => {
+                ev.prevent_default();
+
+                let sibling = match ev.key().as_str() {
+                    "ArrowDown" => element.next_element_sibling(),
+                    _ => element.previous_element_sibling(),
+                };
+
+                if let Some(sibling) = sibling {
+                    focus_element(sibling);
+                }
+            }
+
+            "Escape" | "Delete" if toast.dismiss_mode != DismissMode::None => {
+                ev.prevent_default();
+
+                match element
+                    .next_element_sibling()
+                    .or_else(|| element.previous_element_sibling())
+                {
+                    Some(sibling) => focus_element(sibling),
+                    None => toaster.with_value(ToasterContext::restore_focus),
+                }
+
+                handle.close(CloseReason::Keyboard);
+            }
+
+            _ => {}
+        }
+    };
+
+    // once the enter animation finishes, its final state is made the toast's own
+    // so that the toast can be translated while it is dragged
+    let handle_animation_end = move |ev: ev::AnimationEvent| {
+        if ev.target() == ev.current_target() && !toast.clear_signal.get_untracked() {
+            set_animation_name.set("none".into());
+            set_offsets.set(("auto", "auto"));
+        }
+    };
+
+    let handle_pointer_down = move |ev: ev::PointerEvent| {
+        drag_moved.set_value(false);
+
+        if !swipeable || ev.button() != 0 || toast.clear_signal.get_untracked() {
+            return;
+        }
+
+        let is_interactive = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            .and_then(|target| target.closest(INTERACTIVE_SELECTOR).ok().flatten())
+            .is_some();
+
+        if is_interactive {
+            return;
+        }
+
+        drag_start.set_value(Some((
+            ev.client_x() as f64,
+            ev.client_y() as f64,
+            js_sys::Date::now(),
+        )));
+
+        set_drag_transition.set(0);
+        set_dragging.set(true);
+    };
+
+    let handle_pointer_move = move |ev: ev::PointerEvent| {
+        let Some((start_x, start_y, _)) = drag_start.get_value() else {
+            return;
+        };
+
+        let (direction_x, direction_y) = swipe_direction;
+
+        let offset = (ev.client_x() as f64 - start_x) * direction_x
+            + (ev.client_y() as f64 - start_y) * direction_y;
+
+        // the pointer is only captured once it is dragged, so that taps still click
+        // the links within the toast
+        if offset.abs() > DRAG_SLOP && !drag_moved.get_value() {
+            drag_moved.set_value(true);
+
+            if let Some(element) = ev.current_target() {
+                let element = element.unchecked_into::<web_sys::Element>();
+                _ = element.set_pointer_capture(ev.pointer_id());
+            }
+        }
+
+        set_drag_offset.set(offset.max(0.0));
+    };
+
+    let handle_pointer_up = move |ev: ev::PointerEvent| {
+        let Some((_, _, start_time)) = drag_start.get_value() else {
+            return;
+        };
+
+        drag_start.set_value(None);
+        set_dragging.set(false);
+
+        let offset = drag_offset.get_untracked();
+        let velocity = offset / (js_sys::Date::now() - start_time).max(1.0);
+
+        let is_swipe = offset >= SWIPE_THRESHOLD
+            || (offset > DRAG_SLOP && velocity >= SWIPE_VELOCITY_THRESHOLD);
+
+        if !is_swipe || ev.type_() != "pointerup" {
+            set_drag_transition.set(SNAP_BACK_DURATION);
+            set_drag_offset.set(0.0);
+            return;
+        }
+
+        let Some(element) = ev
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+        else {
+            return;
+        };
+
+        let size = match swipe_direction {
+            (_, 0.0) => element.offset_width(),
+            _ => element.offset_height(),
+        };
+
+        let distance = size as f64 + 12.0 * 2.0;
+        let duration = get_swipe_duration(distance - offset, velocity);
+
+        set_drag_transition.set(duration);
+        set_animation_duration.set(duration);
+        set_drag_offset.set(distance);
+
+        swiped.set_value(true);
+        handle.close(CloseReason::Swipe);
+    };
+
+    let element = store_value(None::<web_sys::HtmlElement>);
+    let node_ref = create_node_ref::<html::Div>();
+
+    node_ref.on_load(move |node| element.set_value(Some((*node).clone().into())));
+
+    // stacked toasts record their measured sizes, from which their stack is laid out
+    if let Some(sizes) = sizes {
+        let id = toast.id;
+
+        create_effect(move |_| {
+            toast.message.track();
+            toast.title.track();
+            toast.description.track();
+            toast.level.track();
+            toast.loading.track();
+            toast.count.track();
+
+            request_animation_frame(move || {
+                let Some(Some(element)) = element.try_get_value() else {
+                    return;
+                };
+
+                let size = measure_toast(&element);
+
+                sizes.try_update(|sizes| {
+                    sizes.insert(id, size);
+                });
+            });
+        });
+
+        on_cleanup(move || {
+            sizes.try_update(|sizes| {
+                sizes.remove(&id);
+            });
+        });
+    }
+
+    // the styles which change while the toast is displayed, which are set through the
+    // CSSOM under class styles once the toast is mounted, so that it is rendered without
+    // a `style` attribute
+    let dynamic_styles = move || {
+        let stack = stack.get();
+        let (left, right) = offsets.get();
+        let (direction_x, direction_y) = swipe_direction;
+        let offset = drag_offset.get();
+
+        InlineStyles::new()
+            .with("left", Some(left))
+            .with("right", Some(right))
+            .with(
+                "translate",
+                Some(format!(
+                    "{}px {}px",
+                    direction_x * offset,
+                    direction_y * offset
+                )),
+            )
+            .with(
+                "transition",
+                Some(format!(
+                    "transform 150ms ease-out, opacity 150ms ease-out, translate {}ms ease-out",
+                    drag_transition.get(),
+                )),
+            )
+            .with(
+                "transition-delay",
+                Some(match stack.is_some_and(|stack| stack.expanded) {
+                    true => "0s, 0s, 0s",
+                    false => "250ms, 0s, 0s",
+                }),
+            )
+            .with(
+                "transform",
+                stack.map(|stack| {
+                    format!("translateY({}px) scaleX({})", stack.translate, stack.scale)
+                }),
+            )
+            .with(
+                "height",
+                stack
+                    .and_then(|stack| stack.height)
+                    .map(|height| format!("{height}px")),
+            )
+            .with("z-index", stack.map(|stack| stack.z_index.to_string()))
+            .with(
+                "opacity",
+                stack.and_then(|stack| stack.hidden.then_some("0")),
+            )
+            .with(
+                "visibility",
+                stack.and_then(|stack| stack.hidden.then_some("hidden")),
+            )
+            .with("animation-name", Some(animation_name.get()))
+            .with(
+                "animation-duration",
+                Some(format!("{}ms", animation_duration.get())),
+            )
+    };
+
+    if class_styles {
+        create_style_effect(node_ref, dynamic_styles);
+    }
+
     view! {
         <div
-            style:width="100%"
-            style:margin="12px 0"
-            style:padding="16px"
-            style:background-color=background_color
-            style:border="1px solid"
-            style:border-color=border_color
-            style:border-radius="4px"
-            style:position="relative"
-            style:cursor=get_cursor(toast.dismissable)
-            style:overflow="hidden"
-            style:box-sizing="border-box"
-            style:left=initial_left
-            style:right=initial_right
-            style:display="flex"
-            style:transition="transform 150ms ease-out, opacity 150ms ease-out"
-            style:transition-delay="250ms, 0s"
-            style:animation-name=animation_name
-            style:animation-duration=format!("{}ms", animation_duration)
-            style:animation-timing-function="linear"
-            style:animation-fill-mode="forwards"
+            node_ref=node_ref
+            tabindex="0"
+            data-leptoaster-toast=""
+            class=move || classes.with_value(|classes| join_classes(&[
+                "leptoaster-toast",
+                get_level_class(&toast.level.get()),
+                &classes.toast,
+                classes.for_level(&toast.level.get()),
+                match toast.dismiss_mode.click() {
+                    true => "leptoaster-toast-clickable",
+                    false => "",
+                },
+                get_touch_action_class(swipeable, swipe_direction),
+                toast.class.as_deref().unwrap_or_default(),
+            ]))
+            {..style_attribute(!class_styles, move || {
+                InlineStyles::new()
+                    .with("width", styled("100%"))
+                    .with("margin", styled("12px 0"))
+                    .with("padding", styled("16px"))
+                    .with("background-color", styled(colors.get().0))
+                    .with("border", styled("1px solid"))
+                    .with("border-color", styled(colors.get().1))
+                    .with("border-radius", styled("4px"))
+                    .with("position", styled("relative"))
+                    .with("cursor", styled(get_cursor(toast.dismiss_mode.click())))
+                    .with("overflow", styled("hidden"))
+                    .with("box-sizing", styled("border-box"))
+                    .with("display", styled("flex"))
+                    .with("touch-action", Some(get_touch_action(swipeable, swipe_direction)))
+                    .with("animation-timing-function", Some("linear"))
+                    .with("animation-fill-mode", Some("forwards"))
+                    .extend(dynamic_styles())
+            })}
+            aria-busy=move || toast.loading.get().to_string()
             on:click=handle_click
+            on:keydown=handle_keydown
+            on:animationend=handle_animation_end
+            on:pointerdown=handle_pointer_down
+            on:pointermove=handle_pointer_move
+            on:pointerup=handle_pointer_up
+            on:pointercancel=handle_pointer_up
+            on:pointerleave=handle_pointer_up
+            on:mouseenter=move |_| set_hovered.set(true)
+            on:mouseleave=move |_| set_hovered.set(false)
+            on:focusin=move |_| set_focused.set(true)
+            on:focusout=move |_| set_focused.set(false)
         >
-            <span
-                style:color=text_color
-                style:font-size="var(--leptoaster-font-size)"
-                style:line-height="var(--leptoaster-line-height)"
-                style:font-family="var(--leptoaster-font-family)"
-                style:font-weight="var(--leptoaster-font-weight)"
-                style:display="inline-block"
-                style:max-width="100%"
-                style:text-overflow="ellipsis"
-                style:overflow="hidden"
-            >
-                {toast.message}
-            </span>
+            {move || {
+                let icon = match (toast.loading.get(), &toast.icon) {
+                    (true, _) => view! {
+                        <span
+                            class="leptoaster-spinner"
+                            {..style_attribute(!class_styles, move || {
+                                InlineStyles::new()
+                                    .with("width", styled("var(--leptoaster-spinner-size)"))
+                                    .with("height", styled("var(--leptoaster-spinner-size)"))
+                                    .with("border", styled("2px solid"))
+                                    .with("border-color", styled("currentColor"))
+                                    .with("border-top-color", styled("transparent"))
+                                    .with("border-radius", styled("50%"))
+                                    .with("box-sizing", styled("border-box"))
+                                    .with("animation", Some("leptoaster-spin 800ms linear infinite"))
+                            })}
+                        />
+                    }.into_view(),
+
+                    (false, ToastIcon::Default) => default_icon(&toast.level.get()),
+                    (false, ToastIcon::Custom(icon)) => icon.run(),
+                    (false, ToastIcon::None) => return None,
+                };
+
+                Some(view! {
+                    <span
+                        aria-hidden="true"
+                        class=classes.with_value(|classes| join_classes(&["leptoaster-icon", &classes.icon]))
+                        {..style_attribute(!class_styles, move || {
+                            InlineStyles::new()
+                                .with("width", styled("var(--leptoaster-icon-size)"))
+                                .with("height", styled("var(--leptoaster-icon-size)"))
+                                .with("margin-right", styled("10px"))
+                                .with("color", styled(get_icon_color(&toast.level.get())))
+                                .with("display", styled("flex"))
+                                .with("align-items", styled("center"))
+                                .with("justify-content", styled("center"))
+                                .with("flex-shrink", styled("0"))
+                        })}
+                    >
+                        {icon}
+                    </span>
+                })
+            }}
 
-            <Show
-                when=move || { toast.expiry.is_some() && toast.progress }
+            <div
+                class="leptoaster-content"
+                {..style_attribute(!class_styles, move || {
+                    InlineStyles::new()
+                        .with("color", styled(colors.get().2))
+                        .with("font-size", styled("var(--leptoaster-font-size)"))
+                        .with("line-height", styled("var(--leptoaster-line-height)"))
+                        .with("font-family", styled("var(--leptoaster-font-family)"))
+                        .with("font-weight", styled("var(--leptoaster-font-weight)"))
+                        .with("display", styled("flex"))
+                        .with("flex-direction", styled("column"))
+                        .with("flex", styled("1"))
+                        .with("min-width", styled("0"))
+                })}
             >
+                {move || toast.title.get().map(|title| view! {
+                    <span
+                        class=classes.with_value(|classes| join_classes(&["leptoaster-title", &classes.title]))
+                        {..style_attribute(!class_styles, move || {
+                            InlineStyles::new()
+                                .with("font-weight", styled("var(--leptoaster-title-font-weight)"))
+                        })}
+                    >
+                        {title}
+                    </span>
+                })}
+
+                {match toast.view {
+                    Some(view) => view.run(),
+
+                    None => (move || {
+                        let message = toast.message.get();
+
+                        (!message.is_empty()).then(|| view! {
+                            <span
+                                class=classes.with_value(|classes| join_classes(&["leptoaster-message", &classes.message]))
+                                {..style_attribute(!class_styles, move || {
+                                    InlineStyles::new()
+                                        .with("display", styled("inline-block"))
+                                        .with("max-width", styled("100%"))
+                                        .with("text-overflow", styled("ellipsis"))
+                                        .with("overflow", styled("hidden"))
+                                })}
+                            >
+                                {message}
+                            </span>
+                        })
+                    }).into_view(),
+                }}
+
+                {move || toast.description.get().map(|description| view! {
+                    <span
+                        class=classes.with_value(|classes| join_classes(&["leptoaster-description", &classes.description]))
+                        {..style_attribute(!class_styles, move || {
+                            InlineStyles::new()
+                                .with("font-weight", styled("var(--leptoaster-description-font-weight)"))
+                                .with("overflow-wrap", styled("anywhere"))
+                        })}
+                    >
+                        {description}
+                    </span>
+                })}
+            </div>
+
+            {move || {
+                let count = toast.count.get();
+
+                (count > 1).then(|| view! {
+                    <span
+                        aria-label=format!("shown {count} times")
+                        class=classes.with_value(|classes| join_classes(&["leptoaster-badge", &classes.badge]))
+                        {..style_attribute(!class_styles, move || {
+                            InlineStyles::new()
+                                .with("color", styled(colors.get().2))
+                                .with("border", styled("1px solid"))
+                                .with("border-color", styled(colors.get().2))
+                                .with("border-radius", styled("10px"))
+                                .with("padding", styled("0 6px"))
+                                .with("margin-left", styled("8px"))
+                                .with("font-size", styled("var(--leptoaster-badge-font-size)"))
+                                .with("line-height", styled("calc(var(--leptoaster-line-height) - 2px)"))
+                                .with("font-family", styled("var(--leptoaster-font-family)"))
+                                .with("font-weight", styled("var(--leptoaster-font-weight)"))
+                                .with("white-space", styled("nowrap"))
+                                .with("flex-shrink", styled("0"))
+                                .with("align-self", styled("flex-start"))
+                        })}
+                    >
+                        {format!("×{count}")}
+                    </span>
+                })
+            }}
+
+            {has_actions.then(|| view! {
                 <div
-                    style:height="var(--leptoaster-progress-height)"
-                    style:width="100%"
-                    style:background-color=text_color
-                    style:position="absolute"
-                    style:bottom="0"
-                    style:left="0"
-                    style:animation-name="leptoaster-progress"
-                    style:animation-duration=format!("{}ms", toast.expiry.unwrap())
-                    style:animation-timing-function="linear"
-                    style:animation-fill-mode="forwards"
-                />
-            </Show>
+                    class="leptoaster-actions"
+                    {..style_attribute(!class_styles, move || {
+                        InlineStyles::new()
+                            .with("display", styled("flex"))
+                            .with("gap", styled("8px"))
+                            .with("margin-left", styled("auto"))
+                            .with("padding-left", styled("12px"))
+                            .with("flex-shrink", styled("0"))
+                    })}
+                >
+                    {toast.cancel.map(|cancel| action_button(cancel, false))}
+                    {toast.action.map(|action| action_button(action, true))}
+                </div>
+            })}
+
+            {toast.dismiss_mode.close_button().then(|| view! {
+                <button
+                    type="button"
+                    aria-label="Close notification"
+                    class=classes.with_value(|classes| join_classes(&["leptoaster-close-button", &classes.close_button]))
+                    {..style_attribute(!class_styles, move || {
+                        InlineStyles::new()
+                            .with("color", styled(colors.get().2))
+                            .with("background-color", styled("transparent"))
+                            .with("border", styled("none"))
+                            .with("padding", styled("0"))
+                            .with("margin-left", styled("12px"))
+                            .with("font-size", styled("var(--leptoaster-close-button-size)"))
+                            .with("line-height", styled("var(--leptoaster-line-height)"))
+                            .with("font-family", styled("var(--leptoaster-font-family)"))
+                            .with("cursor", styled("pointer"))
+                            .with("flex-shrink", styled("0"))
+                            .with("align-self", styled("flex-start"))
+                    })}
+                    on:click=handle_close_click
+                >
+                    "×"
+                </button>
+            })}
+
+            {move || {
+                toast.restart.track();
+                let expiry = toast.expiry.get()?;
+
+                toast.progress.get().then(|| {
+                    let node_ref = create_node_ref::<html::Div>();
+
+                    let dynamic_styles = move || {
+                        InlineStyles::new()
+                            .with("animation-duration", Some(format!("{expiry}ms")))
+                            .with("animation-play-state", Some(get_animation_play_state(paused.get())))
+                    };
+
+                    if class_styles {
+                        create_style_effect(node_ref, dynamic_styles);
+                    }
+
+                    view! {
+                        <div
+                            node_ref=node_ref
+                            class=classes.with_value(|classes| join_classes(&["leptoaster-progress", &classes.progress]))
+                            {..style_attribute(!class_styles, move || {
+                                InlineStyles::new()
+                                    .with("height", styled("var(--leptoaster-progress-height)"))
+                                    .with("width", styled("100%"))
+                                    .with("background-color", styled(colors.get().2))
+                                    .with("position", styled("absolute"))
+                                    .with("bottom", styled("0"))
+                                    .with("left", styled("0"))
+                                    .with("animation-name", Some(get_progress_animation_name(reduced_motion)))
+                                    .with("animation-timing-function", Some("linear"))
+                                    .with("animation-fill-mode", Some("forwards"))
+                                    .extend(dynamic_styles())
+                            })}
+                        />
+                    }
+                })
+            }}
         </div>
     }
 }
 
+fn get_enter_animation_name(animation: &ToastAnimation, position: &ToastPosition) -> String {
+    match animation {
+        ToastAnimation::Slide => get_slide_in_animation_name(position).into(),
+        ToastAnimation::Fade => "leptoaster-fade-in".into(),
+        ToastAnimation::Scale => "leptoaster-scale-in".into(),
+        ToastAnimation::Pop => "leptoaster-pop-in".into(),
+        ToastAnimation::None => "none".into(),
+        ToastAnimation::Custom { enter, .. } => enter.clone(),
+    }
+}
+
+fn get_exit_animation_name(animation: &ToastAnimation, position: &ToastPosition) -> String {
+    match animation {
+        ToastAnimation::Slide => get_slide_out_animation_name(position).into(),
+        ToastAnimation::Fade => "leptoaster-fade-out".into(),
+        ToastAnimation::Scale => "leptoaster-scale-out".into(),
+        ToastAnimation::Pop => "leptoaster-pop-out".into(),
+        ToastAnimation::None => "none".into(),
+        ToastAnimation::Custom { exit, .. } => exit.clone(),
+    }
+}
+
+fn get_animation_duration(animation: &ToastAnimation) -> u32 {
+    match animation {
+        ToastAnimation::Slide | ToastAnimation::Fade | ToastAnimation::Scale => 200,
+        ToastAnimation::Pop => 300,
+        ToastAnimation::None => 0,
+        ToastAnimation::Custom { duration_ms, .. } => *duration_ms,
+    }
+}
+
 fn get_slide_in_animation_name(position: &ToastPosition) -> &'static str {
     match position {
         ToastPosition::TopLeft | ToastPosition::BottomLeft => "leptoaster-slide-in-left",
         ToastPosition::TopRight | ToastPosition::BottomRight => "leptoaster-slide-in-right",
+        ToastPosition::TopCenter => "leptoaster-slide-in-top",
+        ToastPosition::BottomCenter => "leptoaster-slide-in-bottom",
     }
 }
 
@@ -132,6 +836,8 @@ fn get_slide_out_animation_name(position: &ToastPosition) -> &'static str {
     match position {
         ToastPosition::TopLeft | ToastPosition::BottomLeft => "leptoaster-slide-out-left",
         ToastPosition::TopRight | ToastPosition::BottomRight => "leptoaster-slide-out-right",
+        ToastPosition::TopCenter => "leptoaster-slide-out-top",
+        ToastPosition::BottomCenter => "leptoaster-slide-out-bottom",
     }
 }
 
@@ -163,6 +869,15 @@ fn get_colors(level: &ToastLevel) -> (&'static str, &'static str, &'static str)
     }
 }
 
+fn get_icon_color(level: &ToastLevel) -> &'static str {
+    match level {
+        ToastLevel::Info => "var(--leptoaster-info-icon-color)",
+        ToastLevel::Success => "var(--leptoaster-success-icon-color)",
+        ToastLevel::Warn => "var(--leptoaster-warn-icon-color)",
+        ToastLevel::Error => "var(--leptoaster-error-icon-color)",
+    }
+}
+
 fn get_initial_positions(position: &ToastPosition) -> (&'static str, &'static str) {
     match position {
         ToastPosition::TopLeft | ToastPosition::BottomLeft => {
@@ -171,14 +886,102 @@ fn get_initial_positions(position: &ToastPosition) -> (&'static str, &'static st
         ToastPosition::TopRight | ToastPosition::BottomRight => {
             ("auto", "calc((var(--leptoaster-width) + 12px * 2) * -1)")
         }
+        ToastPosition::TopCenter | ToastPosition::BottomCenter => ("auto", "auto"),
+    }
+}
+
+fn get_progress_animation_name(reduced_motion: bool) -> &'static str {
+    match reduced_motion {
+        true => "none",
+        false => "leptoaster-progress",
+    }
+}
+
+fn get_animation_play_state(paused: bool) -> &'static str {
+    match paused {
+        true => "paused",
+        false => "running",
+    }
+}
+
+/// Measures the natural height of the toast, regardless of the height it is given in a
+/// stack, along with the spacing between it and its neighbours.
+fn measure_toast(element: &web_sys::HtmlElement) -> ToastSize {
+    let style = element.style();
+    let height = style.get_property_value("height").unwrap_or_default();
+
+    _ = style.set_property("height", "auto");
+    let natural_height = element.offset_height() as f64;
+    _ = style.set_property("height", &height);
+
+    let margin = |property: &str| {
+        window()
+            .get_computed_style(element)
+            .ok()
+            .flatten()
+            .and_then(|style| style.get_property_value(property).ok())
+            .and_then(|value| value.trim_end_matches("px").parse::<f64>().ok())
+            .unwrap_or_default()
+    };
+
+    ToastSize {
+        height: natural_height,
+        spacing: margin("margin-top").max(margin("margin-bottom")),
+    }
+}
+
+fn get_swipe_direction(position: &ToastPosition) -> (f64, f64) {
+    match position {
+        ToastPosition::TopLeft | ToastPosition::BottomLeft => (-1.0, 0.0),
+        ToastPosition::TopRight | ToastPosition::BottomRight => (1.0, 0.0),
+        ToastPosition::TopCenter => (0.0, -1.0),
+        ToastPosition::BottomCenter => (0.0, 1.0),
+    }
+}
+
+fn get_swipe_duration(distance: f64, velocity: f64) -> u32 {
+    (distance / velocity.max(0.1)).clamp(100.0, 300.0) as u32
+}
+
+fn get_touch_action(swipeable: bool, direction: (f64, f64)) -> &'static str {
+    match (swipeable, direction) {
+        (false, _) => "auto",
+        (true, (_, 0.0)) => "pan-y",
+        (true, _) => "pan-x",
+    }
+}
+
+fn get_touch_action_class(swipeable: bool, direction: (f64, f64)) -> &'static str {
+    match (swipeable, direction) {
+        (false, _) => "",
+        (true, (_, 0.0)) => "leptoaster-toast-swipe-x",
+        (true, _) => "leptoaster-toast-swipe-y",
+    }
+}
+
+pub(crate) fn join_classes(classes: &[&str]) -> String {
+    classes
+        .iter()
+        .filter(|class| !class.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn get_level_class(level: &ToastLevel) -> &'static str {
+    match level {
+        ToastLevel::Info => "leptoaster-toast-info",
+        ToastLevel::Success => "leptoaster-toast-success",
+        ToastLevel::Warn => "leptoaster-toast-warn",
+        ToastLevel::Error => "leptoaster-toast-error",
     }
 }
 
-fn get_cursor(dismissable: bool) -> &'static str {
-    match dismissable {
+fn get_cursor(clickable: bool) -> &'static str {
+    match clickable {
         true => "pointer",
         false => "default",
     }
 }
 
-pub use crate::toast::builder::ToastBuilder;
+pub use crate::toast::{builder::ToastBuilder, handle::ToastHandle, promise::PromiseMessages};
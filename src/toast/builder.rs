@@ -5,21 +5,47 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::rc::Rc;
+
 use leptos::*;
 
-use crate::toast::data::{ToastData, ToastId, ToastLevel, ToastPosition};
+use crate::toast::data::{
+    CloseReason, DismissMode, ToastAction, ToastAnimation, ToastCallback, ToastData, ToastIcon,
+    ToastId, ToastLevel, ToastLive, ToastPosition, ToastView,
+};
 
 #[derive(Clone, Debug)]
 pub struct ToastBuilder {
     message: String,
+    view: Option<ToastView>,
+
+    title: Option<String>,
+    description: Option<String>,
+    icon: ToastIcon,
 
     level: ToastLevel,
+    live: Option<ToastLive>,
 
-    dismissable: bool,
+    dismiss_mode: DismissMode,
     expiry: Option<u32>,
     progress: bool,
+    loading: bool,
+    pausable: bool,
+
+    action: Option<ToastAction>,
+    cancel: Option<ToastAction>,
+    dismiss_on_action: bool,
 
     position: ToastPosition,
+    animation: Option<ToastAnimation>,
+    class: Option<String>,
+
+    dedupe_key: Option<String>,
+    dedupe: bool,
+
+    on_show: Option<ToastCallback>,
+    on_close: Option<ToastCallback<CloseReason>>,
+    on_click: Option<ToastCallback>,
 }
 
 /// Builds a toast, allowing for the custimization of toast message,
@@ -27,10 +53,16 @@ pub struct ToastBuilder {
 ///
 /// The defaults are:
 /// * `level`: `ToastLevel::Info`
-/// * `dismissable`: `true`
+/// * `live`: the `Toaster`'s setting for the toast's level
+/// * `dismiss_mode`: `DismissMode::Click`
 /// * `expiry`: `2_500`
 /// * `progress`: `true`
+/// * `loading`: `false`
+/// * `pausable`: `true`
+/// * `dismiss_on_action`: `true`
 /// * `position`: `ToastPosition::BottomLeft`
+/// * `animation`: the `Toaster`'s animation, which is `ToastAnimation::Slide` by default
+/// * `dedupe`: `false`
 ///
 /// # Examples
 /// ```
@@ -51,14 +83,35 @@ impl ToastBuilder {
     pub fn new(message: &str) -> Self {
         ToastBuilder {
             message: message.into(),
+            view: None,
+
+            title: None,
+            description: None,
+            icon: ToastIcon::Default,
 
             level: ToastLevel::Info,
+            live: None,
 
-            dismissable: true,
+            dismiss_mode: DismissMode::Click,
             expiry: Some(2_500),
             progress: true,
+            loading: false,
+            pausable: true,
+
+            action: None,
+            cancel: None,
+            dismiss_on_action: true,
 
             position: ToastPosition::BottomLeft,
+            animation: None,
+            class: None,
+
+            dedupe_key: None,
+            dedupe: false,
+
+            on_show: None,
+            on_close: None,
+            on_click: None,
         }
     }
 
@@ -66,6 +119,74 @@ impl ToastBuilder {
         self.message = level.as_ref().into();
         self
     }
+    /// Sets a view to render as the body of the toast in place of the message,
+    /// allowing for links, formatted text, or multi-line content. The message is
+    /// still used wherever the toast needs a textual representation.
+    ///
+    /// # Examples
+    /// ```
+    /// use leptos::*;
+    ///
+    /// leptoaster::ToastBuilder::new("Saved the document.")
+    ///     .with_view(|| view! { <span>"Saved " <b>"the document"</b> "."</span> });
+    /// ```
+    #[must_use]
+    pub fn with_view<V: IntoView>(mut self, view: impl Fn() -> V + 'static) -> Self {
+        self.view = Some(ToastView(Rc::new(move || view().into_view())));
+        self
+    }
+
+    /// Sets the title of the toast, which is displayed in bold above the message.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::default()
+    ///     .with_title("Upload complete")
+    ///     .with_description("All 3 files were uploaded successfully.");
+    /// ```
+    #[must_use]
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description of the toast, which is displayed below the message
+    /// and wraps onto multiple lines rather than being truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("Upload complete.")
+    ///     .with_description("All 3 files were uploaded successfully.");
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Overrides the default icon of the toast's level with the supplied view,
+    /// or disables the icon on `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use leptos::*;
+    ///
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_icon(Some(ViewFn::from(|| view! { <span>"🍞"</span> })));
+    ///
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_icon(None); // hides the icon.
+    /// ```
+    #[must_use]
+    pub fn with_icon(mut self, icon: Option<ViewFn>) -> Self {
+        self.icon = match icon {
+            Some(icon) => ToastIcon::Custom(ToastView(Rc::new(move || icon.run()))),
+            None => ToastIcon::None,
+        };
+
+        self
+    }
+
     /// Sets the level of the toast.
     ///
     /// # Examples
@@ -79,6 +200,20 @@ impl ToastBuilder {
         self
     }
 
+    /// Sets how urgently screen readers announce the toast, overriding the
+    /// `Toaster`'s setting for the toast's level.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_live(leptoaster::ToastLive::Assertive); // interrupts the screen reader.
+    /// ```
+    #[must_use]
+    pub fn with_live(mut self, live: ToastLive) -> Self {
+        self.live = Some(live);
+        self
+    }
+
     /// Sets the dismissable flag of the toast to allow or disallow the toast
     /// from being dismissable on click.
     ///
@@ -89,7 +224,34 @@ impl ToastBuilder {
     /// ```
     #[must_use]
     pub fn with_dismissable(mut self, dismissable: bool) -> Self {
-        self.dismissable = dismissable;
+        self.dismiss_mode = DismissMode::from_flags(dismissable, self.dismiss_mode.close_button());
+        self
+    }
+
+    /// Sets the close button flag of the toast to show or hide a close button
+    /// which dismisses the toast, independently of it being dismissable on click.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_close_button(true); // shows the close button.
+    /// ```
+    #[must_use]
+    pub fn with_close_button(mut self, close_button: bool) -> Self {
+        self.dismiss_mode = DismissMode::from_flags(self.dismiss_mode.click(), close_button);
+        self
+    }
+
+    /// Sets the way in which the toast can be dismissed by the user.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_dismiss_mode(leptoaster::DismissMode::CloseButton); // only dismissable with the close button.
+    /// ```
+    #[must_use]
+    pub fn with_dismiss_mode(mut self, dismiss_mode: DismissMode) -> Self {
+        self.dismiss_mode = dismiss_mode;
         self
     }
 
@@ -106,6 +268,20 @@ impl ToastBuilder {
         self
     }
 
+    /// Sets the loading flag of the toast to show or hide a loading spinner
+    /// next to the message.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_loading(true); // shows the loading spinner.
+    /// ```
+    #[must_use]
+    pub fn with_loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     /// Sets the expiry time of the toast in milliseconds, or disables it on `None`.
     ///
     /// # Examples
@@ -119,6 +295,72 @@ impl ToastBuilder {
         self
     }
 
+    /// Sets the pausable flag of the toast to allow or disallow its expiry from
+    /// being paused while the toast is hovered or focused, or while the page is hidden.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_pausable(false); // the toast expires even while hovered.
+    /// ```
+    #[must_use]
+    pub fn with_pausable(mut self, pausable: bool) -> Self {
+        self.pausable = pausable;
+        self
+    }
+
+    /// Adds an action button with the supplied label to the toast, which runs the
+    /// supplied callback when clicked.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("Item deleted.")
+    ///     .with_action("Undo", || { /* restore the item */ });
+    /// ```
+    #[must_use]
+    pub fn with_action(mut self, label: &str, callback: impl Fn() + 'static) -> Self {
+        self.action = Some(ToastAction {
+            label: label.into(),
+            callback: ToastCallback(Rc::new(move |()| callback())),
+        });
+
+        self
+    }
+
+    /// Adds a cancel button with the supplied label to the toast, which runs the
+    /// supplied callback when clicked.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("Upload failed.")
+    ///     .with_action("Retry", || { /* retry the upload */ })
+    ///     .with_cancel("Ignore", || {});
+    /// ```
+    #[must_use]
+    pub fn with_cancel(mut self, label: &str, callback: impl Fn() + 'static) -> Self {
+        self.cancel = Some(ToastAction {
+            label: label.into(),
+            callback: ToastCallback(Rc::new(move |()| callback())),
+        });
+
+        self
+    }
+
+    /// Sets the flag which defines whether or not the toast is dismissed once one
+    /// of its action or cancel buttons is clicked.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_action("View", || {})
+    ///     .with_dismiss_on_action(false); // keeps the toast open after clicking "View".
+    /// ```
+    #[must_use]
+    pub fn with_dismiss_on_action(mut self, dismiss_on_action: bool) -> Self {
+        self.dismiss_on_action = dismiss_on_action;
+        self
+    }
+
     /// Sets the position of the toast.
     ///
     /// # Examples
@@ -132,24 +374,168 @@ impl ToastBuilder {
         self
     }
 
+    /// Sets the enter and exit animation of the toast, overriding the animation
+    /// of the `Toaster`.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_animation(leptoaster::ToastAnimation::Fade); // fades the toast in and out.
+    /// ```
+    #[must_use]
+    pub fn with_animation(mut self, animation: ToastAnimation) -> Self {
+        self.animation = Some(animation);
+        self
+    }
+
+    /// Sets a class which is added to the toast alongside the `Toaster`'s classes.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .with_class("rounded-lg shadow-md"); // adds the classes to the toast.
+    /// ```
+    #[must_use]
+    pub fn with_class(mut self, class: impl Into<String>) -> Self {
+        self.class = Some(class.into());
+        self
+    }
+
+    /// Sets the key which identifies duplicates of the toast. While a toast with the
+    /// same key is in the toaster, toasting this one restarts the existing toast's
+    /// expiry and increments its counter badge rather than displaying a new toast.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("Could not reach the server.")
+    ///     .with_dedupe_key("network-error");
+    /// ```
+    #[must_use]
+    pub fn with_dedupe_key(mut self, dedupe_key: impl Into<String>) -> Self {
+        self.dedupe_key = Some(dedupe_key.into());
+        self
+    }
+
+    /// Sets the dedupe flag of the toast to collapse it into an existing toast with
+    /// the same message and level. A key set with `with_dedupe_key` takes precedence.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("Could not reach the server.")
+    ///     .with_dedupe(true); // collapses identical toasts into one.
+    /// ```
+    #[must_use]
+    pub fn with_dedupe(mut self, dedupe: bool) -> Self {
+        self.dedupe = dedupe;
+        self
+    }
+
+    /// Sets a callback which is run once the toast is displayed.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .on_show(|| leptos::logging::log!("toast shown"));
+    /// ```
+    #[must_use]
+    pub fn on_show(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_show = Some(ToastCallback(Rc::new(move |()| callback())));
+        self
+    }
+
+    /// Sets a callback which is run with the reason the toast was closed once it
+    /// starts being dismissed, or once it is removed from the toaster.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .on_close(|reason| leptos::logging::log!("toast closed: {reason:?}"));
+    /// ```
+    #[must_use]
+    pub fn on_close(mut self, callback: impl Fn(CloseReason) + 'static) -> Self {
+        self.on_close = Some(ToastCallback(Rc::new(callback)));
+        self
+    }
+
+    /// Sets a callback which is run when the body of the toast is clicked,
+    /// regardless of whether or not the toast is dismissable on click.
+    ///
+    /// # Examples
+    /// ```
+    /// leptoaster::ToastBuilder::new("My toast message.")
+    ///     .on_click(|| leptos::logging::log!("toast clicked"));
+    /// ```
+    #[must_use]
+    pub fn on_click(mut self, callback: impl Fn() + 'static) -> Self {
+        self.on_click = Some(ToastCallback(Rc::new(move |()| callback())));
+        self
+    }
+
+    /// Returns the key which identifies duplicates of the toast, if it is deduplicated.
+    pub(crate) fn dedupe_key(&self) -> Option<String> {
+        self.dedupe_key.clone().or_else(|| {
+            self.dedupe
+                .then(|| format!("{:?}:{}", self.level, self.message))
+        })
+    }
+
     /// Builds the toast into a `ToastData` with the supplied ID.
     #[must_use]
     pub fn build(self, id: ToastId) -> ToastData {
+        let dedupe_key = self.dedupe_key();
+
         ToastData {
             id,
-            message: self.message,
+            message: create_rw_signal(self.message),
+            view: self.view,
+
+            title: create_rw_signal(self.title),
+            description: create_rw_signal(self.description),
+            icon: self.icon,
 
-            level: self.level,
+            level: create_rw_signal(self.level),
+            live: self.live,
 
-            dismissable: self.dismissable,
-            expiry: self.expiry,
-            progress: self.progress,
+            dismiss_mode: self.dismiss_mode,
+            expiry: create_rw_signal(self.expiry),
+            restart: create_rw_signal(0),
+            progress: create_rw_signal(self.progress),
+            loading: create_rw_signal(self.loading),
+            pausable: self.pausable,
+
+            action: self.action,
+            cancel: self.cancel,
+            dismiss_on_action: self.dismiss_on_action,
 
             position: self.position,
+            animation: self.animation,
+            class: self.class,
+
+            dedupe_key,
+            count: create_rw_signal(1),
+
+            on_show: self.on_show,
+            on_close: self.on_close,
+            on_click: self.on_click,
 
             clear_signal: create_rw_signal(false),
+            close_reason: store_value(None),
         }
     }
+
+    /// Applies the message, title, description, level, expiry, progress, and loading
+    /// flags of the builder onto an existing toast, and restarts the toast's expiry
+    /// timer.
+    pub(crate) fn apply(self, toast: &ToastData) {
+        toast.message.set(self.message);
+        toast.title.set(self.title);
+        toast.description.set(self.description);
+        toast.level.set(self.level);
+        toast.progress.set(self.progress);
+        toast.loading.set(self.loading);
+        toast.expiry.set(self.expiry);
+        toast.restart.update(|restart| *restart += 1);
+    }
 }
 impl Default for ToastBuilder {
     fn default() -> Self {
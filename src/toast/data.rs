@@ -5,11 +5,14 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::{fmt, rc::Rc};
+
 use leptos::*;
+use serde::{Deserialize, Serialize};
 
 pub type ToastId = u64;
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum ToastLevel {
 	Info,
 	Success,
@@ -17,27 +20,276 @@ pub enum ToastLevel {
 	Error,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum ToastPosition {
 	TopLeft,
+	TopCenter,
 	TopRight,
 	BottomRight,
+	BottomCenter,
 	BottomLeft,
 }
 
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ToastAnimation {
+	#[default]
+	Slide,
+	Fade,
+	Scale,
+	Pop,
+	None,
+	Custom {
+		enter: String,
+		exit: String,
+		duration_ms: u32,
+	},
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ToastLive {
+	Polite,
+	Assertive,
+	Off,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ToasterMode {
+	#[default]
+	Fixed,
+	Contained,
+	Inline,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ToastLiveLevels {
+	pub info: ToastLive,
+	pub success: ToastLive,
+	pub warn: ToastLive,
+	pub error: ToastLive,
+}
+
+impl ToastLiveLevels {
+	#[must_use]
+	pub fn for_level(&self, level: &ToastLevel) -> ToastLive {
+		match level {
+			ToastLevel::Info => self.info,
+			ToastLevel::Success => self.success,
+			ToastLevel::Warn => self.warn,
+			ToastLevel::Error => self.error,
+		}
+	}
+}
+
+impl Default for ToastLiveLevels {
+	fn default() -> Self {
+		ToastLiveLevels {
+			info: ToastLive::Polite,
+			success: ToastLive::Polite,
+			warn: ToastLive::Assertive,
+			error: ToastLive::Assertive,
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct ToasterClasses {
+	pub container: String,
+	pub toast: String,
+	pub info: String,
+	pub success: String,
+	pub warn: String,
+	pub error: String,
+	pub icon: String,
+	pub title: String,
+	pub message: String,
+	pub description: String,
+	pub badge: String,
+	pub action: String,
+	pub close_button: String,
+	pub progress: String,
+}
+
+impl ToasterClasses {
+	#[must_use]
+	pub fn for_level(&self, level: &ToastLevel) -> &str {
+		match level {
+			ToastLevel::Info => &self.info,
+			ToastLevel::Success => &self.success,
+			ToastLevel::Warn => &self.warn,
+			ToastLevel::Error => &self.error,
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ToasterHotkey {
+	pub code: String,
+	pub alt: bool,
+	pub ctrl: bool,
+	pub shift: bool,
+	pub meta: bool,
+}
+
+impl ToasterHotkey {
+	pub(crate) fn matches(&self, ev: &web_sys::KeyboardEvent) -> bool {
+		ev.code() == self.code
+			&& ev.alt_key() == self.alt
+			&& ev.ctrl_key() == self.ctrl
+			&& ev.shift_key() == self.shift
+			&& ev.meta_key() == self.meta
+	}
+
+	pub(crate) fn label(&self) -> String {
+		let key = self
+			.code
+			.trim_start_matches("Key")
+			.trim_start_matches("Digit");
+
+		[
+			(self.ctrl, "Ctrl"),
+			(self.alt, "Alt"),
+			(self.shift, "Shift"),
+			(self.meta, "Meta"),
+			(true, key),
+		]
+		.into_iter()
+		.filter_map(|(pressed, name)| pressed.then_some(name))
+		.collect::<Vec<_>>()
+		.join("+")
+	}
+}
+
+impl Default for ToasterHotkey {
+	fn default() -> Self {
+		ToasterHotkey {
+			code: "KeyT".into(),
+			alt: true,
+			ctrl: false,
+			shift: false,
+			meta: false,
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum DismissMode {
+	Click,
+	CloseButton,
+	Both,
+	None,
+}
+
+impl DismissMode {
+	pub(crate) fn from_flags(click: bool, close_button: bool) -> Self {
+		match (click, close_button) {
+			(true, false) => DismissMode::Click,
+			(false, true) => DismissMode::CloseButton,
+			(true, true) => DismissMode::Both,
+			(false, false) => DismissMode::None,
+		}
+	}
+
+	pub(crate) fn click(self) -> bool {
+		matches!(self, DismissMode::Click | DismissMode::Both)
+	}
+
+	pub(crate) fn close_button(self) -> bool {
+		matches!(self, DismissMode::CloseButton | DismissMode::Both)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CloseReason {
+	Click,
+	CloseButton,
+	Action,
+	Keyboard,
+	Swipe,
+	Expired,
+	Dismissed,
+	Cleared,
+	Removed,
+}
+
+#[derive(Clone)]
+pub struct ToastCallback<T = ()>(pub Rc<dyn Fn(T)>);
+
+impl<T> ToastCallback<T> {
+	pub fn call(&self, value: T) {
+		(self.0)(value);
+	}
+}
+
+impl<T> fmt::Debug for ToastCallback<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("ToastCallback").finish_non_exhaustive()
+	}
+}
+
+#[derive(Clone)]
+pub struct ToastView(pub Rc<dyn Fn() -> View>);
+
+impl ToastView {
+	pub fn run(&self) -> View {
+		(self.0)()
+	}
+}
+
+impl fmt::Debug for ToastView {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("ToastView").finish_non_exhaustive()
+	}
+}
+
+#[derive(Clone, Debug)]
+pub enum ToastIcon {
+	Default,
+	Custom(ToastView),
+	None,
+}
+
+#[derive(Clone, Debug)]
+pub struct ToastAction {
+	pub label: String,
+	pub callback: ToastCallback,
+}
+
 #[derive(Clone, Debug)]
 pub struct ToastData {
 	pub id: ToastId,
 
-	pub message: String,
+	pub message: RwSignal<String>,
+	pub view: Option<ToastView>,
+
+	pub title: RwSignal<Option<String>>,
+	pub description: RwSignal<Option<String>>,
+	pub icon: ToastIcon,
 
-	pub level: ToastLevel,
+	pub level: RwSignal<ToastLevel>,
+	pub live: Option<ToastLive>,
 
-	pub dismissable: bool,
-	pub expiry: Option<u32>,
-	pub progress: bool,
+	pub dismiss_mode: DismissMode,
+	pub expiry: RwSignal<Option<u32>>,
+	pub restart: RwSignal<u32>,
+	pub progress: RwSignal<bool>,
+	pub loading: RwSignal<bool>,
+	pub pausable: bool,
+
+	pub action: Option<ToastAction>,
+	pub cancel: Option<ToastAction>,
+	pub dismiss_on_action: bool,
 
 	pub position: ToastPosition,
+	pub animation: Option<ToastAnimation>,
+
+	pub dedupe_key: Option<String>,
+	pub class: Option<String>,
+	pub count: RwSignal<u32>,
+
+	pub on_show: Option<ToastCallback>,
+	pub on_close: Option<ToastCallback<CloseReason>>,
+	pub on_click: Option<ToastCallback>,
 
 	pub clear_signal: RwSignal<bool>,
+	pub close_reason: StoredValue<Option<CloseReason>>,
 }
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use leptos::*;
+
+use crate::toast::data::{CloseReason, ToastData, ToastId};
+
+/// A handle to a toast which has been added to the toaster, allowing it to be
+/// referred to after it has been displayed.
+///
+/// # Examples
+/// ```
+/// #[leptos::component]
+/// fn Component() -> impl leptos::IntoView {
+///     let toaster = leptoaster::expect_toaster();
+///     let handle = toaster.info("Submitting form...");
+///
+///     handle.dismiss();
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ToastHandle {
+    id: ToastId,
+    clear_signal: RwSignal<bool>,
+    close_reason: StoredValue<Option<CloseReason>>,
+    pending: RwSignal<Vec<ToastData>>,
+}
+
+impl ToastHandle {
+    pub(crate) fn new(toast: &ToastData, pending: RwSignal<Vec<ToastData>>) -> Self {
+        ToastHandle {
+            id: toast.id,
+            clear_signal: toast.clear_signal,
+            close_reason: toast.close_reason,
+            pending,
+        }
+    }
+
+    /// Returns the ID of the toast.
+    #[must_use]
+    pub fn id(&self) -> ToastId {
+        self.id
+    }
+
+    /// Dismisses the toast, playing its slide-out animation before removing it
+    /// from the toaster. Does nothing if the toast is already being dismissed.
+    pub fn dismiss(&self) {
+        self.close(CloseReason::Dismissed);
+    }
+
+    /// Starts dismissing the toast for the supplied reason, unless it is already
+    /// being dismissed.
+    pub(crate) fn close(&self, reason: CloseReason) {
+        if self.clear_signal.get_untracked() {
+            return;
+        }
+
+        self.close_reason.set_value(Some(reason));
+        self.clear_signal.set(true);
+
+        // pending toasts are never displayed, so they are withdrawn from the
+        // pending queue rather than being removed once their animation ends
+        let mut withdrawn = None;
+
+        self.pending.update(|pending| {
+            if let Some(index) = pending.iter().position(|toast| toast.id == self.id) {
+                withdrawn = Some(pending.remove(index));
+            }
+        });
+
+        if let Some(on_close) = withdrawn.and_then(|toast| toast.on_close) {
+            on_close.call(reason);
+        }
+    }
+
+    /// Returns a signal which is `true` while the toast is displayed, and becomes
+    /// `false` once the toast starts being dismissed. Toasts waiting in the pending
+    /// queue are not visible until they are displayed.
+    #[must_use]
+    pub fn is_visible(&self) -> Signal<bool> {
+        let id = self.id;
+        let clear_signal = self.clear_signal;
+        let pending = self.pending;
+
+        Signal::derive(move || {
+            !clear_signal.get()
+                && !pending.with(|pending| pending.iter().any(|toast| toast.id == id))
+        })
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use leptos::*;
+
+use crate::toast::data::ToastLevel;
+
+/// Renders the default icon of the supplied toast level as an inline SVG which
+/// inherits its stroke color from the surrounding text color.
+pub(crate) fn default_icon(level: &ToastLevel) -> View {
+    let paths = match level {
+        ToastLevel::Info => view! {
+            <circle cx="12" cy="12" r="10" />
+            <line x1="12" y1="16" x2="12" y2="12" />
+            <line x1="12" y1="8" x2="12.01" y2="8" />
+        }
+        .into_view(),
+
+        ToastLevel::Success => view! {
+            <circle cx="12" cy="12" r="10" />
+            <polyline points="8 12 11 15 16 9" />
+        }
+        .into_view(),
+
+        ToastLevel::Warn => view! {
+            <path d="M10.29 3.86 1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z" />
+            <line x1="12" y1="9" x2="12" y2="13" />
+            <line x1="12" y1="17" x2="12.01" y2="17" />
+        }
+        .into_view(),
+
+        ToastLevel::Error => view! {
+            <circle cx="12" cy="12" r="10" />
+            <line x1="15" y1="9" x2="9" y2="15" />
+            <line x1="9" y1="9" x2="15" y2="15" />
+        }
+        .into_view(),
+    };
+
+    view! {
+        <svg
+            xmlns="http://www.w3.org/2000/svg"
+            viewBox="0 0 24 24"
+            width="100%"
+            height="100%"
+            fill="none"
+            stroke="currentColor"
+            stroke-width="2"
+            stroke-linecap="round"
+            stroke-linejoin="round"
+            aria-hidden="true"
+        >
+            {paths}
+        </svg>
+    }
+    .into_view()
+}
@@ -0,0 +1,41 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/// The messages of a promise toast. The `loading` message is displayed while
+/// the future is pending, after which the toast is updated with the message
+/// produced by either `success` or `error`, depending on the future's result.
+///
+/// # Examples
+/// ```
+/// let messages = leptoaster::PromiseMessages::new(
+///     "Saving...",
+///     |name: &String| format!("Saved {name}."),
+///     |error: &String| format!("Could not save: {error}"),
+/// );
+/// ```
+pub struct PromiseMessages<T, E> {
+    pub loading: String,
+    pub success: Box<dyn Fn(&T) -> String>,
+    pub error: Box<dyn Fn(&E) -> String>,
+}
+
+impl<T, E> PromiseMessages<T, E> {
+    /// Constructs the promise messages with the supplied loading message and
+    /// success and error message functions.
+    #[must_use]
+    pub fn new(
+        loading: &str,
+        success: impl Fn(&T) -> String + 'static,
+        error: impl Fn(&E) -> String + 'static,
+    ) -> Self {
+        PromiseMessages {
+            loading: loading.into(),
+            success: Box::new(success),
+            error: Box::new(error),
+        }
+    }
+}
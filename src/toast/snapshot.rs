@@ -0,0 +1,116 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use leptos::*;
+use serde::{Deserialize, Serialize};
+
+use crate::toast::data::{
+    DismissMode, ToastAnimation, ToastData, ToastIcon, ToastId, ToastLevel, ToastLive,
+    ToastPosition,
+};
+
+/// The serializable parts of a toast, which carry the toasts shown while rendering on
+/// the server over to the client. Custom views and icons, actions, and callbacks cannot
+/// be serialized, so restored toasts are shown without them.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub(crate) struct ToastSnapshot {
+    pub id: ToastId,
+
+    pub message: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub icon: bool,
+
+    pub level: ToastLevel,
+    pub live: Option<ToastLive>,
+
+    pub dismiss_mode: DismissMode,
+    pub expiry: Option<u32>,
+    pub progress: bool,
+    pub loading: bool,
+    pub pausable: bool,
+
+    pub position: ToastPosition,
+    pub animation: Option<ToastAnimation>,
+
+    pub dedupe_key: Option<String>,
+    pub class: Option<String>,
+    pub count: u32,
+}
+
+impl ToastSnapshot {
+    pub fn new(toast: &ToastData) -> Self {
+        ToastSnapshot {
+            id: toast.id,
+
+            message: toast.message.get_untracked(),
+            title: toast.title.get_untracked(),
+            description: toast.description.get_untracked(),
+            icon: !matches!(toast.icon, ToastIcon::None),
+
+            level: toast.level.get_untracked(),
+            live: toast.live,
+
+            dismiss_mode: toast.dismiss_mode,
+            expiry: toast.expiry.get_untracked(),
+            progress: toast.progress.get_untracked(),
+            loading: toast.loading.get_untracked(),
+            pausable: toast.pausable,
+
+            position: toast.position.clone(),
+            animation: toast.animation.clone(),
+
+            dedupe_key: toast.dedupe_key.clone(),
+            class: toast.class.clone(),
+            count: toast.count.get_untracked(),
+        }
+    }
+
+    /// Builds a live toast from the snapshot, keeping the ID it was given on the server.
+    pub fn into_data(self) -> ToastData {
+        ToastData {
+            id: self.id,
+            message: create_rw_signal(self.message),
+            view: None,
+
+            title: create_rw_signal(self.title),
+            description: create_rw_signal(self.description),
+            icon: match self.icon {
+                true => ToastIcon::Default,
+                false => ToastIcon::None,
+            },
+
+            level: create_rw_signal(self.level),
+            live: self.live,
+
+            dismiss_mode: self.dismiss_mode,
+            expiry: create_rw_signal(self.expiry),
+            restart: create_rw_signal(0),
+            progress: create_rw_signal(self.progress),
+            loading: create_rw_signal(self.loading),
+            pausable: self.pausable,
+
+            action: None,
+            cancel: None,
+            dismiss_on_action: false,
+
+            position: self.position,
+            animation: self.animation,
+            class: self.class,
+
+            dedupe_key: self.dedupe_key,
+            count: create_rw_signal(self.count),
+
+            on_show: None,
+            on_close: None,
+            on_click: None,
+
+            clear_signal: create_rw_signal(false),
+            close_reason: store_value(None),
+        }
+    }
+}
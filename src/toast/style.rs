@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use leptos::*;
+
+/// The inline styles of an element. Properties without a value are left out of the
+/// element's `style` attribute, and are removed when set through the CSSOM.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub(crate) struct InlineStyles(Vec<(&'static str, Option<String>)>);
+
+impl InlineStyles {
+    pub fn new() -> Self {
+        InlineStyles::default()
+    }
+
+    /// Adds the supplied property, which is left out if it has no value.
+    pub fn with(mut self, property: &'static str, value: Option<impl Into<String>>) -> Self {
+        self.0.push((property, value.map(Into::into)));
+        self
+    }
+
+    /// Adds the properties of the supplied styles.
+    pub fn extend(mut self, styles: InlineStyles) -> Self {
+        self.0.extend(styles.0);
+        self
+    }
+
+    /// Returns the value of the `style` attribute, or `None` if no property has a value
+    /// so that the attribute is not rendered.
+    pub fn to_attribute(&self) -> Option<String> {
+        let declarations = self
+            .0
+            .iter()
+            .filter_map(|(property, value)| Some(format!("{property}: {};", value.as_ref()?)))
+            .collect::<Vec<_>>();
+
+        (!declarations.is_empty()).then(|| declarations.join(" "))
+    }
+
+    /// Sets the properties on the supplied element through the CSSOM, which a strict
+    /// Content-Security-Policy allows, unlike the `style` attribute.
+    pub fn apply(&self, element: &web_sys::HtmlElement) {
+        let style = element.style();
+
+        for (property, value) in &self.0 {
+            _ = match value {
+                Some(value) => style.set_property(property, value),
+                None => style.remove_property(property).map(|_| ()),
+            };
+        }
+    }
+}
+
+/// Returns the `style` attribute of an element, to be spread onto it, which is reactive
+/// to the supplied styles. Nothing is spread while `inline` is not set, so that elements
+/// styled with classes are rendered without a `style` attribute.
+pub(crate) fn style_attribute(
+    inline: bool,
+    styles: impl Fn() -> InlineStyles + 'static,
+) -> Vec<(&'static str, Attribute)> {
+    match inline {
+        true => vec![("style", (move || styles().to_attribute()).into_attribute())],
+        false => Vec::new(),
+    }
+}
+
+/// Sets the supplied styles on the element of the node reference through the CSSOM
+/// whenever they change, once the element is mounted.
+pub(crate) fn create_style_effect(
+    node_ref: NodeRef<html::Div>,
+    styles: impl Fn() -> InlineStyles + 'static,
+) {
+    create_effect(move |_| {
+        let styles = styles();
+
+        if let Some(element) = node_ref.get() {
+            styles.apply(&element);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn properties_without_a_value_are_left_out() {
+        let styles = InlineStyles::new()
+            .with("width", Some("100%"))
+            .with("height", None::<String>)
+            .with("z-index", Some(2.to_string()));
+
+        assert_eq!(
+            styles.to_attribute().as_deref(),
+            Some("width: 100%; z-index: 2;")
+        );
+    }
+
+    #[test]
+    fn styles_without_any_value_have_no_attribute() {
+        let styles = InlineStyles::new().with("width", None::<&str>);
+
+        assert_eq!(styles.to_attribute(), None);
+        assert_eq!(InlineStyles::new().to_attribute(), None);
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+
+// the longest time the timer sleeps before checking whether it was paused
+// or restarted in the meantime
+const MAX_SLEEP: f64 = 250.0;
+
+/// A pausable countdown of a toast's expiry, which tracks the time remaining
+/// until the toast expires.
+#[derive(Clone, Copy)]
+pub(crate) struct ExpiryTimer {
+    state: StoredValue<TimerState>,
+}
+
+#[derive(Default)]
+struct TimerState {
+    run: u64,
+    remaining: f64,
+    paused: bool,
+    resumed_at: f64,
+}
+
+impl ExpiryTimer {
+    pub fn new() -> Self {
+        ExpiryTimer {
+            state: store_value(TimerState::default()),
+        }
+    }
+
+    /// Starts the countdown from the supplied expiry, cancelling any previous
+    /// countdown. Returns the ID of the new run to be waited on.
+    pub fn start(&self, expiry: u32) -> u64 {
+        self.state.update_value(|state| {
+            state.run += 1;
+            state.remaining = f64::from(expiry);
+            state.resumed_at = now();
+        });
+
+        self.state.with_value(|state| state.run)
+    }
+
+    /// Cancels the current countdown, if any.
+    pub fn cancel(&self) {
+        self.state.update_value(|state| state.run += 1);
+    }
+
+    pub fn pause(&self) {
+        self.state.update_value(|state| {
+            if !state.paused {
+                state.paused = true;
+                state.remaining -= now() - state.resumed_at;
+            }
+        });
+    }
+
+    pub fn resume(&self) {
+        self.state.update_value(|state| {
+            if state.paused {
+                state.paused = false;
+                state.resumed_at = now();
+            }
+        });
+    }
+
+    /// Waits until the supplied run has counted down to zero. Returns `false` if
+    /// the run was cancelled or restarted before elapsing.
+    pub async fn wait(&self, run: u64) -> bool {
+        loop {
+            let Some(remaining) = self.remaining(run) else {
+                return false;
+            };
+
+            if remaining <= 0.0 {
+                return true;
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            TimeoutFuture::new(remaining.min(MAX_SLEEP).ceil() as u32).await;
+        }
+    }
+
+    /// Returns the time remaining in the supplied run, or `None` if the run was
+    /// cancelled or restarted.
+    fn remaining(&self, run: u64) -> Option<f64> {
+        self.state
+            .try_with_value(|state| {
+                (state.run == run).then(|| match state.paused {
+                    true => state.remaining,
+                    false => state.remaining - (now() - state.resumed_at),
+                })
+            })
+            .flatten()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now() -> f64 {
+    js_sys::Date::now()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64() * 1_000.0)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    fn with_timer(test: impl FnOnce(ExpiryTimer)) {
+        let runtime = create_runtime();
+        test(ExpiryTimer::new());
+        runtime.dispose();
+    }
+
+    #[test]
+    fn counts_down_from_the_expiry() {
+        with_timer(|timer| {
+            let run = timer.start(1_000);
+            thread::sleep(Duration::from_millis(20));
+
+            let remaining = timer.remaining(run).unwrap();
+            assert!(remaining <= 980.0 && remaining > 0.0);
+        });
+    }
+
+    #[test]
+    fn restarting_cancels_the_previous_run() {
+        with_timer(|timer| {
+            let first = timer.start(1_000);
+            thread::sleep(Duration::from_millis(20));
+            let second = timer.start(1_000);
+
+            assert_eq!(timer.remaining(first), None);
+            assert!(timer.remaining(second).unwrap() > 980.0);
+        });
+    }
+
+    #[test]
+    fn cancelling_ends_the_run() {
+        with_timer(|timer| {
+            let run = timer.start(1_000);
+            timer.cancel();
+
+            assert_eq!(timer.remaining(run), None);
+        });
+    }
+
+    #[test]
+    fn pausing_stops_the_countdown() {
+        with_timer(|timer| {
+            let run = timer.start(1_000);
+            timer.pause();
+
+            let paused = timer.remaining(run).unwrap();
+            thread::sleep(Duration::from_millis(20));
+
+            assert_eq!(timer.remaining(run), Some(paused));
+
+            timer.resume();
+            thread::sleep(Duration::from_millis(20));
+
+            assert!(timer.remaining(run).unwrap() < paused);
+        });
+    }
+
+    #[test]
+    fn restarting_while_paused_keeps_the_timer_paused() {
+        with_timer(|timer| {
+            timer.start(1_000);
+            timer.pause();
+
+            let run = timer.start(500);
+            thread::sleep(Duration::from_millis(20));
+
+            assert_eq!(timer.remaining(run), Some(500.0));
+        });
+    }
+}
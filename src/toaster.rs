@@ -6,24 +6,108 @@
  */
 
 pub mod context;
+pub mod stack;
+pub mod theme;
 
-use crate::toaster::context::ToasterContext;
+use std::collections::HashMap;
+
+use crate::toast::{join_classes, style_attribute, InlineStyles};
+use crate::toaster::{
+    context::{get_toaster_selector, NamedToasters, ToasterContext},
+    stack::{get_toast_stack, StackLayout, ToastSize},
+    theme::ToasterTheme,
+};
 use crate::{
-    toast::{Toast, ToastData, ToastPosition},
+    toast::{
+        Toast, ToastAnimation, ToastData, ToastId, ToastLive, ToastLiveLevels, ToastPosition,
+        ToasterClasses, ToasterHotkey, ToasterMode,
+    },
     ToastBuilder,
 };
 use leptos::*;
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// The toaster's stylesheet, which the `Toaster` injects in a `<style>` element unless
+/// `external_stylesheet` is set, in which case it should be served by the application.
+///
+/// # Examples
+/// ```no_run
+/// std::fs::write(
+///     "public/leptoaster.css",
+///     leptoaster::LEPTOASTER_CSS,
+/// ).unwrap();
+/// ```
+pub const LEPTOASTER_CSS: &str = include_str!("leptoaster.css");
 
 const CONTAINER_POSITIONS: &[ToastPosition] = &[
     ToastPosition::TopLeft,
+    ToastPosition::TopCenter,
     ToastPosition::TopRight,
     ToastPosition::BottomRight,
+    ToastPosition::BottomCenter,
     ToastPosition::BottomLeft,
 ];
 
-/// Creates the toaster containers as fixed-position elements on the corners of the screen.
+/// Creates the toaster containers as fixed-position elements on the corners and the
+/// top and bottom centers of the screen.
+///
+/// The `mode` prop instead positions the containers on the corners and centers of the
+/// nearest positioned ancestor with `ToasterMode::Contained`, such as within a modal or
+/// a panel, or renders them in the normal flow of the document with `ToasterMode::Inline`.
+/// The `portal` prop mounts the containers in `document.body`, or in the `mount` element
+/// if one is supplied, so that they are not clipped or offset by the `Toaster`'s
+/// ancestors. Portals are only mounted in the browser, so server-rendered pages do not
+/// include the containers, which are added once the page is hydrated.
+///
+/// Takes an optional prop that defines whether or not the toasts are stacked, and an
+/// optional prop that limits the number of toasts visible in each position. Toasts over
+/// the limit wait in a pending queue, and their expiry starts once they are displayed.
+/// Stacks show up to `stack_depth` toasts, each peeking out `stack_gap` pixels from
+/// behind the one in front of it, and are laid out from the toasts' measured heights.
+/// Stacks expand while they are hovered or contain focus.
+/// The `animation` prop sets the enter and exit animation of toasts which do not set
+/// their own, defaulting to `ToastAnimation::Slide`. The `reduced_motion` prop overrides
+/// the user's `prefers-reduced-motion` setting, under which toasts fade rather than move,
+/// stacked toasts are not transformed, and progress bars are not animated.
+///
+/// The containers are grouped in a single region labelled `Notifications`, which also
+/// holds a polite and an assertive ARIA live region. Screen readers announce the text of
+/// each toast through one of them as the toast is added, as both are rendered before any
+/// toast is. The `live_levels` prop sets how urgently toasts of each level are announced,
+/// which toasts can override with `with_live`.
+///
+/// The `theme` prop sets the colors of the toasts, and can be changed while the toaster
+/// is displayed. It defaults to `ToasterTheme::light`, and `ToasterTheme::auto` switches
+/// between the light and dark themes with the user's `prefers-color-scheme` setting.
+/// The theme only applies to the toaster's own containers, so named toasters can be
+/// given their own themes.
+///
+/// The `unstyled` prop removes the inline styles of the containers and toasts, other than
+/// those which drive the toasts' animations, so that they can be styled with classes.
+/// Each container is marked with its position in `data-leptoaster-position`. The
+/// `classes` prop sets the classes of the containers and of each part of the toasts,
+/// which toasts can add to with `with_class`.
+///
+/// For a strict Content-Security-Policy, the `nonce` prop sets the nonce of the injected
+/// `<style>` elements, defaulting to the nonce provided by Leptos, if any. Alternatively,
+/// the `external_stylesheet` prop stops the stylesheet from being injected, so that it
+/// can be served from `LEPTOASTER_CSS`. The `class_styles` prop styles the containers
+/// and toasts with the stylesheet's classes rather than inline styles, rendering them
+/// without `style` attributes.
+///
+/// The `name` prop renders the toasts of the toaster provided with `provide_named_toaster`
+/// under the same name, rather than those of the toaster provided with `provide_toaster`.
+///
+/// The `hotkey` prop, `Alt+T` by default, moves focus to the first toast. Focused
+/// toasts can be navigated with the arrow keys and dismissed with `Escape` or `Delete`,
+/// after which focus returns to the previously focused element. Setting the prop to
+/// `None` disables the hotkey. Named toasters have no hotkey unless one is set, so that
+/// a single key press does not move focus into several toasters at once.
 ///
-/// Takes an optional prop that defines whether or not the toasts are stacked.
+/// With server-side rendering, the toasts added while rendering on the server are
+/// serialized into the page once its resources resolve, so they are only rendered by the
+/// async and streaming rendering modes. Synchronous rendering with `render_to_string`
+/// is not supported, and renders the containers without their toasts.
 ///
 /// # Examples
 /// ```
@@ -33,186 +117,321 @@ const CONTAINER_POSITIONS: &[ToastPosition] = &[
 /// #[component]
 /// fn App() -> impl IntoView {
 ///     view! {
-///         <Toaster stacked={true} />
+///         <Toaster stacked={true} max_visible={3} />
 ///     }
 /// }
 /// ```
 #[component]
-pub fn Toaster(#[prop(optional, into)] stacked: MaybeSignal<bool>) -> impl IntoView {
-    let toaster = expect_toaster();
+pub fn Toaster(
+    #[prop(optional, into)] name: Option<String>,
+    #[prop(optional)] mode: ToasterMode,
+    #[prop(optional)] portal: bool,
+    #[prop(optional, into)] mount: Option<web_sys::Element>,
+    #[prop(optional, into)] stacked: MaybeSignal<bool>,
+    #[prop(default = 5)] stack_depth: usize,
+    #[prop(default = 8.0)] stack_gap: f64,
+    #[prop(optional)] max_visible: Option<usize>,
+    #[prop(optional)] animation: ToastAnimation,
+    #[prop(optional)] reduced_motion: Option<bool>,
+    #[prop(optional)] live_levels: ToastLiveLevels,
+    #[prop(optional)] hotkey: Option<Option<ToasterHotkey>>,
+    #[prop(optional)] unstyled: bool,
+    #[prop(optional)] classes: ToasterClasses,
+    #[prop(optional, into)] theme: MaybeSignal<ToasterTheme>,
+    #[prop(optional, into)] nonce: Option<String>,
+    #[prop(optional)] external_stylesheet: bool,
+    #[prop(optional)] class_styles: bool,
+) -> impl IntoView {
+    let toaster = match &name {
+        Some(name) => expect_named_toaster(name),
+        None => expect_toaster(),
+    };
+
+    toaster.set_max_visible(max_visible);
+
+    // the toasts find their toaster through the context, which is provided again in
+    // case it is a named toaster
+    provide_context(toaster.clone());
+
+    let hotkey = hotkey.unwrap_or_else(|| name.is_none().then(ToasterHotkey::default));
+
+    let region_label = store_value(match &hotkey {
+        Some(hotkey) => format!("Notifications ({})", hotkey.label()),
+        None => "Notifications".into(),
+    });
+
+    if let Some(hotkey) = hotkey {
+        let toaster = toaster.clone();
+
+        let handle = window_event_listener(ev::keydown, move |ev| {
+            if hotkey.matches(&ev) {
+                ev.prevent_default();
+                toaster.focus_toasts();
+            }
+        });
+
+        on_cleanup(move || handle.remove());
+    }
 
-    view! {
-        <style>
-            "
-			:root {
-				--leptoaster-width: 320px;
-				--leptoaster-max-width: 80vw;
-				--leptoaster-z-index: 9999;
-
-				--leptoaster-font-family: Arial;
-				--leptoaster-font-size: 14px;
-				--leptoaster-line-height: 20px;
-				--leptoaster-font-weight: 600;
-
-				--leptoaster-progress-height: 2px;
-
-				--leptoaster-info-background-color: #ffffff;
-				--leptoaster-info-border-color: #222222;
-				--leptoaster-info-text-color: #222222;
-
-				--leptoaster-success-background-color: #4caf50;
-				--leptoaster-success-border-color: #2e7d32;
-				--leptoaster-success-text-color: #ffffff;
-
-				--leptoaster-warn-background-color: #ff9800;
-				--leptoaster-warn-border-color: #ff8f00;
-				--leptoaster-warn-text-color: #ffffff;
-
-				--leptoaster-error-background-color: #f44336;
-				--leptoaster-error-border-color: #c62828;
-				--leptoaster-error-text-color: #ffffff;
-			}
-
-			.leptoaster-stack-container-bottom:hover > div,
-			.leptoaster-stack-container-top:hover > div {
-				opacity: 1 !important;
-				transform: translateY(0) scaleX(1) !important;
-				transition-delay: 0s !important;
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(1),
-			.leptoaster-stack-container-top > div:nth-child(1) {
-				z-index: 9999;
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(2),
-			.leptoaster-stack-container-top > div:nth-child(2) {
-				z-index: 9998;
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(2) {
-				transform: translateY(62px) scaleX(0.98);
-			}
-
-			.leptoaster-stack-container-top > div:nth-child(2) {
-				transform: translateY(-62px) scaleX(0.98);
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(3),
-			.leptoaster-stack-container-top > div:nth-child(3) {
-				z-index: 9997;
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(3) {
-				transform: translateY(124px) scaleX(0.96);
-			}
-
-			.leptoaster-stack-container-top > div:nth-child(3) {
-				transform: translateY(-124px) scaleX(0.96);
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(4),
-			.leptoaster-stack-container-top > div:nth-child(4) {
-				z-index: 9996;
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(4) {
-				transform: translateY(186px) scaleX(0.94);
-			}
-
-			.leptoaster-stack-container-top > div:nth-child(4) {
-				transform: translateY(-186px) scaleX(0.94);
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(5),
-			.leptoaster-stack-container-top > div:nth-child(5) {
-				z-index: 9995;
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(5) {
-				transform: translateY(248px) scaleX(0.92);
-			}
-
-			.leptoaster-stack-container-top > div:nth-child(5) {
-				transform: translateY(-248px) scaleX(0.92);
-			}
-
-			.leptoaster-stack-container-bottom > div:nth-last-child(n+6),
-			.leptoaster-stack-container-top > div:nth-child(n+6) {
-				opacity: 0;
-			}
-
-			@keyframes leptoaster-slide-in-left {
-				from { left: calc((var(--leptoaster-width) + 12px * 2) * -1) }
-				to { left: 0 }
-			}
-
-			@keyframes leptoaster-slide-out-left {
-				from { left: 0 }
-				to { left: calc((var(--leptoaster-width) + 12px * 2) * -1) }
-			}
-
-			@keyframes leptoaster-slide-in-right {
-				from { right: calc((var(--leptoaster-width) + 12px * 2) * -1) }
-				to { right: 0 }
-			}
-
-			@keyframes leptoaster-slide-out-right {
-				from { right: 0 }
-				to { right: calc((var(--leptoaster-width) + 12px * 2) * -1) }
-			}
-
-			@keyframes leptoaster-progress {
-				from { width: 100%; }
-				to { width: 0; }
-			}
-			"
-        </style>
-
-        <For
-            each=move || CONTAINER_POSITIONS
-            key=|position| get_container_id(position)
-            let:position
-        >
-            <Show
-                when=move || !is_container_empty(position)
+    // the toasts added while rendering on the server are serialized into the page with
+    // this resource, which is only read once the page has been rendered
+    let server_toasts = create_resource(|| (), {
+        let toaster = toaster.clone();
+        move |()| {
+            let toaster = toaster.clone();
+            async move { toaster.snapshot() }
+        }
+    });
+
+    let restore_toasts = {
+        let toaster = store_value(toaster.clone());
+        move |snapshots| toaster.with_value(|toaster| toaster.restore(snapshots))
+    };
+
+    let nonce = nonce.or_else(|| leptos::nonce::use_nonce().map(|nonce| nonce.to_string()));
+
+    let class_styles = class_styles && !unstyled;
+    let inline_styles = !unstyled && !class_styles;
+
+    let container_class = match class_styles {
+        true => "leptoaster-styled",
+        false => "",
+    };
+
+    let selector = store_value(get_toaster_selector(name.as_deref()));
+    let name = store_value(name);
+    let animation = store_value(animation);
+    let sizes = create_rw_signal(HashMap::<ToastId, ToastSize>::new());
+    let classes = store_value(classes);
+    let prefers_reduced_motion = create_rw_signal(false);
+
+    create_effect(move |_| {
+        let Some(query) = window()
+            .match_media("(prefers-reduced-motion: reduce)")
+            .ok()
+            .flatten()
+        else {
+            return;
+        };
+
+        prefers_reduced_motion.set(query.matches());
+
+        // follows changes to the setting while the application is open
+        let handle_change = Closure::<dyn Fn(web_sys::MediaQueryListEvent)>::new(
+            move |ev: web_sys::MediaQueryListEvent| prefers_reduced_motion.set(ev.matches()),
+        )
+        .into_js_value();
+
+        _ = query.add_event_listener_with_callback("change", handle_change.unchecked_ref());
+
+        on_cleanup(move || {
+            _ = query.remove_event_listener_with_callback("change", handle_change.unchecked_ref());
+        });
+    });
+
+    let reduced_motion =
+        Signal::derive(move || reduced_motion.unwrap_or_else(|| prefers_reduced_motion.get()));
+
+    let containers = move || {
+        view! {
+            <For
+                each=move || CONTAINER_POSITIONS
+                key=|position| get_container_id(position)
+                let:position
             >
-                <div
-                    class=get_container_class(stacked.get(), position)
-                    style:width="var(--leptoaster-width)"
-                    style:max-width="var(--leptoaster-max-width)"
-                    style:margin=get_container_margin(position)
-                    style:position="fixed"
-                    style:inset=get_container_inset(position)
-                    style:z-index="var(--leptoaster-z-index)"
-                >
+                {
+                    let (hovered, set_hovered) = create_signal(false);
+                    let (focused, set_focused) = create_signal(false);
+
+                    // the IDs of the container's toasts, from the front of the stack to the back
+                    let ids = create_memo(move |_| {
+                        toaster.queue.with(|queue| {
+                            queue
+                                .iter()
+                                .rev()
+                                .filter(|toast| toast.position.eq(position))
+                                .map(|toast| toast.id)
+                                .collect::<Vec<ToastId>>()
+                        })
+                    });
+
+                    let layout = move || StackLayout {
+                        depth: stack_depth,
+                        gap: stack_gap,
+                        bottom: is_bottom_position(position),
+                        expanded: hovered.get() || focused.get(),
+                        reduced_motion: reduced_motion.get(),
+                    };
+
+                    view! {
+                        <div
+                            class=classes.with_value(|classes| join_classes(&[
+                                get_container_class(stacked.get(), position).unwrap_or_default(),
+                                container_class,
+                                get_mode_class(mode),
+                                &classes.container,
+                            ]))
+                            class:leptoaster-container-center=!unstyled && is_center_position(position)
+                            class:leptoaster-reduced-motion=reduced_motion
+                            data-leptoaster-position=get_container_id(position)
+                            {..style_attribute(inline_styles, move || {
+                                InlineStyles::new()
+                                    .with("width", Some("var(--leptoaster-width)"))
+                                    .with("max-width", Some(get_container_max_width(mode)))
+                                    .with("margin", Some(get_container_margin(position, mode)))
+                                    .with("position", Some(get_container_position(mode)))
+                                    .with("inset", get_container_inset(position, mode))
+                                    .with("z-index", Some("var(--leptoaster-z-index)"))
+                            })}
+                            on:mouseenter=move |_| set_hovered.set(true)
+                            on:mouseleave=move |_| set_hovered.set(false)
+                            on:focusin=move |_| set_focused.set(true)
+                            on:focusout=move |_| set_focused.set(false)
+                        >
+                            {
+                                let toasts = move || view! {
+                                    <For
+                                        each=move || {
+                                            let toasts = toaster.queue.get();
+
+                                            match position {
+                                                ToastPosition::BottomLeft | ToastPosition::BottomCenter | ToastPosition::BottomRight => {
+                                                    toasts.iter()
+                                                        .filter(|toast| toast.position.eq(position)).cloned()
+                                                        .collect::<Vec<ToastData>>()
+                                                },
+
+                                                ToastPosition::TopLeft | ToastPosition::TopCenter | ToastPosition::TopRight => {
+                                                    toasts.iter()
+                                                        .filter(|toast| toast.position.eq(position)).cloned()
+                                                        .rev()
+                                                        .collect::<Vec<ToastData>>()
+                                                },
+                                            }
+                                        }
+                                        key=|toast| toast.id
+                                        let:toast
+                                    >
+                                        {
+                                            let id = toast.id;
+
+                                            let stack = create_memo(move |_| {
+                                                if !stacked.get() {
+                                                    return None;
+                                                }
+
+                                                ids.with(|ids| {
+                                                    let index = ids.iter().position(|other| *other == id)?;
+                                                    sizes.with(|sizes| Some(get_toast_stack(index, ids, sizes, layout())))
+                                                })
+                                            });
+
+                                            view! {
+                                                <Toast
+                                                    toast={toast}
+                                                    default_animation={animation.get_value()}
+                                                    reduced_motion={reduced_motion}
+                                                    unstyled={!inline_styles}
+                                                    class_styles={class_styles}
+                                                    classes={classes.get_value()}
+                                                    stack={stack}
+                                                    sizes={sizes}
+                                                    container_paused={Signal::derive(move || hovered.get() || focused.get())}
+                                                />
+                                            }
+                                        }
+                                    </For>
+                                };
+
+                                // the server renders its toasts once the page has added them, and the
+                                // client restores them before they are hydrated into live toasts,
+                                // while the container itself is rendered right away as a live region
+                                match cfg!(any(feature = "ssr", feature = "hydrate")) {
+                                    true => view! {
+                                        <Suspense>
+                                            {move || {
+                                                if let Some(snapshots) = server_toasts.get() {
+                                                    untrack(|| restore_toasts(snapshots));
+                                                }
+
+                                                toasts()
+                                            }}
+                                        </Suspense>
+                                    }.into_view(),
+
+                                    false => toasts().into_view(),
+                                }
+                            }
+                        </div>
+                    }
+                }
+            </For>
+        }
+    };
+
+    // screen readers ignore live regions which are inserted along with their content, so
+    // the toasts are only announced once the live regions are in the document
+    let (announcing, set_announcing) = create_signal(false);
+    create_effect(move |_| request_animation_frame(move || set_announcing.set(true)));
+
+    let announcer = move |live: ToastLive| {
+        view! {
+            <div
+                class="leptoaster-announcer"
+                role=get_announcer_role(live)
+                aria-live=get_aria_live(live)
+                aria-atomic="false"
+            >
+                {move || announcing.get().then(|| view! {
                     <For
+                        // a repeated toast is announced again each time its counter is incremented
                         each=move || {
-                            let toasts = toaster.queue.get();
-
-                            match position {
-                                ToastPosition::BottomLeft | ToastPosition::BottomRight => {
-                                    toasts.iter()
-                                        .filter(|toast| toast.position.eq(position)).cloned()
-                                        .collect::<Vec<ToastData>>()
-                                },
-
-                                ToastPosition::TopLeft | ToastPosition::TopRight => {
-                                    toasts.iter()
-                                        .filter(|toast| toast.position.eq(position)).cloned()
-                                        .rev()
-                                        .collect::<Vec<ToastData>>()
-                                },
-                            }
+                            toaster.queue.get()
+                                .into_iter()
+                                .filter(|toast| toast.live.unwrap_or_else(|| live_levels.for_level(&toast.level.get())) == live)
+                                .map(|toast| (toast.count.get(), toast))
+                                .collect::<Vec<_>>()
                         }
-                        key=|toast| toast.id
-                        let:toast
-                    >
-                        <Toast toast={toast} />
-                    </For>
-                </div>
-            </Show>
-        </For>
+                        key=|(count, toast)| (toast.id, *count)
+                        children=|(_, toast)| view! {
+                            <p>{move || get_announcement(&toast)}</p>
+                        }
+                    />
+                })}
+            </div>
+        }
+    };
+
+    let regions = move || {
+        view! {
+            <div
+                role="region"
+                aria-label=region_label.get_value()
+                data-leptoaster-toaster=name.get_value().unwrap_or_default()
+            >
+                {announcer(ToastLive::Polite)}
+                {announcer(ToastLive::Assertive)}
+                {containers()}
+            </div>
+        }
+    };
+
+    view! {
+        {(!external_stylesheet).then(|| view! {
+            <style nonce=nonce.clone()>{LEPTOASTER_CSS}</style>
+        })}
+
+        {move || theme.with(|theme| {
+            (theme != &ToasterTheme::default()).then(|| view! {
+                <style nonce=nonce.clone()>{theme.to_css(&selector.get_value())}</style>
+            })
+        })}
+
+        {match (portal, mount) {
+            (_, Some(mount)) => view! { <Portal mount>{regions()}</Portal> }.into_view(),
+            (true, None) => view! { <Portal>{regions()}</Portal> }.into_view(),
+            (false, None) => regions().into_view(),
+        }}
     }
 }
 
@@ -238,48 +457,280 @@ pub fn expect_toaster() -> ToasterContext {
     expect_context::<ToasterContext>()
 }
 
-fn is_container_empty(position: &ToastPosition) -> bool {
-    !expect_toaster()
-        .queue
-        .get()
-        .iter()
-        .any(|toast| toast.position.eq(position))
+/// Provides a toaster under the supplied name, which is independent of the toaster
+/// provided with `provide_toaster`. Its toasts are rendered by the `Toaster` with the
+/// same `name`, such as to show toasts within a panel of the application. The toaster
+/// is removed once the component which provided it is unmounted.
+///
+/// # Examples
+/// ```
+/// use leptos::*;
+/// use leptoaster::*;
+///
+/// #[component]
+/// fn Editor() -> impl IntoView {
+///     provide_named_toaster("editor");
+///
+///     view! {
+///         <Toaster name="editor" />
+///     }
+/// }
+/// ```
+pub fn provide_named_toaster(name: &str) {
+    provide_named_toaster_context(name, None);
+}
+
+/// Provides a toaster under the supplied name with the supplied defaults.
+pub fn provide_named_toaster_with_defaults(name: &str, defaults: ToastBuilder) {
+    provide_named_toaster_context(name, Some(defaults));
+}
+
+/// Returns the toaster provided under the supplied name.
+///
+/// # Panics
+/// Panics if no toaster has been provided under the name.
+///
+/// # Examples
+/// ```
+/// #[leptos::component]
+/// fn Component() -> impl leptos::IntoView {
+///     let toaster = leptoaster::expect_named_toaster("editor");
+///
+///     toaster.info("Saved the draft.");
+/// }
+/// ```
+#[must_use]
+pub fn expect_named_toaster(name: &str) -> ToasterContext {
+    use_context::<NamedToasters>()
+        .and_then(|toasters| toasters.0.borrow().get(name).cloned())
+        .unwrap_or_else(|| panic!("no toaster has been provided with the name `{name}`"))
+}
+
+fn provide_named_toaster_context(name: &str, defaults: Option<ToastBuilder>) {
+    let toasters = use_context::<NamedToasters>().unwrap_or_else(|| {
+        let toasters = NamedToasters::default();
+        provide_context(toasters.clone());
+        toasters
+    });
+
+    if toasters.0.borrow().contains_key(name) {
+        return;
+    }
+
+    let toaster = ToasterContext::new(Some(name.into()), defaults);
+    let queue = toaster.queue;
+
+    toasters.0.borrow_mut().insert(name.into(), toaster);
+
+    // the toaster's signals are disposed along with the caller, which may be unmounted
+    // before the registry, so the toaster is removed from the registry with them
+    let name = name.to_owned();
+
+    on_cleanup(move || {
+        let mut toasters = toasters.0.borrow_mut();
+
+        if toasters
+            .get(&name)
+            .is_some_and(|toaster| toaster.queue == queue)
+        {
+            toasters.remove(&name);
+        }
+    });
 }
 
 fn get_container_id(position: &ToastPosition) -> &'static str {
     match position {
         ToastPosition::TopLeft => "top_left",
+        ToastPosition::TopCenter => "top_center",
         ToastPosition::TopRight => "top_right",
         ToastPosition::BottomRight => "bottom_right",
+        ToastPosition::BottomCenter => "bottom_center",
         ToastPosition::BottomLeft => "bottom_left",
     }
 }
 
-fn get_container_inset(position: &ToastPosition) -> &'static str {
-    match position {
+fn get_container_position(mode: ToasterMode) -> &'static str {
+    match mode {
+        ToasterMode::Fixed => "fixed",
+        ToasterMode::Contained => "absolute",
+        ToasterMode::Inline => "relative",
+    }
+}
+
+fn get_container_inset(position: &ToastPosition, mode: ToasterMode) -> Option<&'static str> {
+    if mode == ToasterMode::Inline {
+        return None;
+    }
+
+    let inset = match position {
         ToastPosition::TopLeft => "0 auto auto 0",
+        ToastPosition::TopCenter => "0 0 auto 0",
         ToastPosition::TopRight => "0 0 auto auto",
         ToastPosition::BottomRight => "auto 0 0 auto",
+        ToastPosition::BottomCenter => "auto 0 0 0",
         ToastPosition::BottomLeft => "auto 0 0 0",
+    };
+
+    Some(inset)
+}
+
+fn get_container_margin(position: &ToastPosition, mode: ToasterMode) -> &'static str {
+    match (position, mode) {
+        (ToastPosition::TopCenter | ToastPosition::BottomCenter, _) => "0 auto",
+        (_, ToasterMode::Inline) => "0",
+        (ToastPosition::TopLeft | ToastPosition::BottomLeft, _) => "0 0 0 12px",
+        (ToastPosition::TopRight | ToastPosition::BottomRight, _) => "0 12px 0 0",
     }
 }
 
-fn get_container_margin(position: &ToastPosition) -> &'static str {
-    match position {
-        ToastPosition::TopLeft | ToastPosition::BottomLeft => "0 0 0 12px",
-        ToastPosition::TopRight | ToastPosition::BottomRight => "0 12px 0 0",
+fn get_container_max_width(mode: ToasterMode) -> &'static str {
+    match mode {
+        ToasterMode::Fixed => "var(--leptoaster-max-width)",
+        ToasterMode::Contained => "min(var(--leptoaster-max-width), calc(100% - 24px))",
+        ToasterMode::Inline => "100%",
+    }
+}
+
+fn get_mode_class(mode: ToasterMode) -> &'static str {
+    match mode {
+        ToasterMode::Fixed => "",
+        ToasterMode::Contained => "leptoaster-contained",
+        ToasterMode::Inline => "leptoaster-inline",
     }
 }
 
+fn is_bottom_position(position: &ToastPosition) -> bool {
+    matches!(
+        position,
+        ToastPosition::BottomLeft | ToastPosition::BottomCenter | ToastPosition::BottomRight
+    )
+}
+
+fn is_center_position(position: &ToastPosition) -> bool {
+    matches!(
+        position,
+        ToastPosition::TopCenter | ToastPosition::BottomCenter
+    )
+}
+
+fn get_announcer_role(live: ToastLive) -> &'static str {
+    match live {
+        ToastLive::Assertive => "alert",
+        ToastLive::Polite | ToastLive::Off => "status",
+    }
+}
+
+fn get_aria_live(live: ToastLive) -> &'static str {
+    match live {
+        ToastLive::Polite => "polite",
+        ToastLive::Assertive => "assertive",
+        ToastLive::Off => "off",
+    }
+}
+
+/// Returns the text of the toast which is announced by screen readers.
+fn get_announcement(toast: &ToastData) -> String {
+    [
+        toast.title.get(),
+        Some(toast.message.get()),
+        toast.description.get(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|text| !text.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
 fn get_container_class(stacked: bool, position: &ToastPosition) -> Option<&'static str> {
     if !stacked {
         return None;
     }
 
     match position {
-        ToastPosition::BottomLeft | ToastPosition::BottomRight => {
+        ToastPosition::BottomLeft | ToastPosition::BottomCenter | ToastPosition::BottomRight => {
             Some("leptoaster-stack-container-bottom")
         }
-        ToastPosition::TopLeft | ToastPosition::TopRight => Some("leptoaster-stack-container-top"),
+        ToastPosition::TopLeft | ToastPosition::TopCenter | ToastPosition::TopRight => {
+            Some("leptoaster-stack-container-top")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Renders the supplied view on the server, waiting for its resources to resolve.
+    #[cfg(feature = "ssr")]
+    fn render_to_string(view: impl FnOnce() -> View + 'static) -> String {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        tokio::task::LocalSet::new().block_on(&runtime, leptos::ssr::render_to_string_async(view))
+    }
+
+    #[test]
+    fn named_toaster_is_provided_again_once_remounted() {
+        let runtime = create_runtime();
+        provide_context(NamedToasters::default());
+
+        let mount = as_child_of_current_owner(|()| {
+            provide_named_toaster("editor");
+            expect_named_toaster("editor").info("Opened the editor.");
+        });
+
+        let ((), disposer) = mount(());
+        drop(disposer);
+
+        let toasters = expect_context::<NamedToasters>();
+        assert!(!toasters.0.borrow().contains_key("editor"));
+
+        let ((), _disposer) = mount(());
+        let toaster = expect_named_toaster("editor");
+        assert_eq!(toaster.queue.with_untracked(Vec::len), 1);
+
+        runtime.dispose();
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn containers_are_grouped_in_one_region_with_prerendered_live_regions() {
+        let html = render_to_string(|| {
+            provide_toaster();
+            expect_toaster().error("Could not save the draft.");
+
+            view! { <Toaster /> }.into_view()
+        });
+
+        assert_eq!(html.matches(r#"role="region""#).count(), 1);
+        assert!(html.contains(r#"role="status" aria-live="polite""#));
+        assert!(html.contains(r#"role="alert" aria-live="assertive""#));
+
+        // the toast itself is rendered without a live region of its own
+        assert!(html.contains("Could not save the draft."));
+        assert_eq!(html.matches("aria-live=").count(), 2);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn class_styled_toasters_are_rendered_without_style_attributes() {
+        let html = render_to_string(|| {
+            provide_toaster();
+            expect_toaster().toast(
+                ToastBuilder::new("Could not save the draft.")
+                    .with_title("Error")
+                    .with_description("The server is unavailable.")
+                    .with_close_button(true)
+                    .with_progress(true)
+                    .with_action("Retry", || ()),
+            );
+
+            view! { <Toaster class_styles=true external_stylesheet=true /> }.into_view()
+        });
+
+        assert!(html.contains("Could not save the draft."));
+        assert!(!html.contains("style="));
     }
 }
@@ -5,11 +5,15 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, future::Future, rc::Rc};
 
 use leptos::*;
+use wasm_bindgen::JsCast;
 
-use crate::toast::{ToastBuilder, ToastData, ToastId, ToastLevel};
+use crate::toast::{
+    CloseReason, PromiseMessages, ToastBuilder, ToastData, ToastHandle, ToastId, ToastLevel,
+    ToastPosition, ToastSnapshot,
+};
 
 /// The global context of the toaster. You should provide this as a global context
 /// in your root component to allow any component in your application to toast
@@ -24,11 +28,19 @@ use crate::toast::{ToastBuilder, ToastData, ToastId, ToastLevel};
 ///  ```
 #[derive(Clone, Debug)]
 pub struct ToasterContext {
-    stats: Rc<RefCell<ToasterStats>>,
+    stats: StoredValue<ToasterStats>,
     pub queue: RwSignal<Vec<ToastData>>,
+    pub pending: RwSignal<Vec<ToastData>>,
+    max_visible: StoredValue<Option<usize>>,
+    previous_focus: StoredValue<Option<web_sys::Element>>,
     defaults: Option<ToastBuilder>,
+    name: Option<String>,
 }
 
+/// The toasters provided with a name, which are looked up by `expect_named_toaster`.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct NamedToasters(pub Rc<RefCell<HashMap<String, ToasterContext>>>);
+
 #[derive(Clone, Default, Debug)]
 struct ToasterStats {
     visible: u32,
@@ -36,14 +48,34 @@ struct ToasterStats {
 }
 
 impl ToasterContext {
-    pub(crate) fn new_with_defaults(defaults: ToastBuilder) -> Self {
+    pub(crate) fn new(name: Option<String>, defaults: Option<ToastBuilder>) -> Self {
         ToasterContext {
-            stats: Rc::new(RefCell::new(ToasterStats::default())),
+            stats: store_value(ToasterStats::default()),
             queue: create_rw_signal(Vec::new()),
-            defaults: Some(defaults),
+            pending: create_rw_signal(Vec::new()),
+            max_visible: store_value(None),
+            previous_focus: store_value(None),
+            defaults,
+            name,
         }
     }
+
+    pub(crate) fn new_with_defaults(defaults: ToastBuilder) -> Self {
+        ToasterContext::new(None, Some(defaults))
+    }
+
+    /// Returns the name of the toaster, if it was provided with one.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     /// Adds the supplied toast to the toast queue, displaying it onto the screen.
+    /// If the toast's position already displays the maximum number of visible toasts,
+    /// the toast waits in the pending queue until another toast is removed.
+    /// If the toast is a duplicate of one already in the toaster, the existing toast's
+    /// counter is incremented and its expiry restarted instead.
+    /// Returns a `ToastHandle` which can be used to refer to the toast later.
     ///
     /// # Examples
     /// ```
@@ -57,15 +89,29 @@ impl ToasterContext {
     ///     );
     /// }
     /// ```
-    pub fn toast(&self, builder: ToastBuilder) {
-        let toast = builder.build(self.stats.borrow().total + 1);
+    pub fn toast(&self, builder: ToastBuilder) -> ToastHandle {
+        if let Some(duplicate) = builder
+            .dedupe_key()
+            .and_then(|dedupe_key| self.find_duplicate(&dedupe_key))
+        {
+            duplicate.count.update(|count| *count += 1);
+            duplicate.restart.update(|restart| *restart += 1);
 
-        let mut queue = self.queue.get_untracked();
-        queue.push(toast);
-        self.queue.set(queue);
+            return ToastHandle::new(&duplicate, self.pending);
+        }
+
+        let toast = builder.build(self.stats.with_value(|stats| stats.total) + 1);
+        let handle = ToastHandle::new(&toast, self.pending);
+
+        self.stats.update_value(|stats| stats.total += 1);
+
+        if self.is_position_full(&toast.position) {
+            self.pending.update(|pending| pending.push(toast));
+        } else {
+            self.show(toast);
+        }
 
-        self.stats.borrow_mut().visible += 1;
-        self.stats.borrow_mut().total += 1;
+        handle
     }
 
     /// Quickly display an `info` toast with default parameters. For more customization,
@@ -79,15 +125,8 @@ impl ToasterContext {
     ///     toaster.info("My toast message.");
     /// }
     /// ```
-    pub fn info(&self, message: &str) {
-        self.toast(
-            self.defaults
-                .as_ref()
-                .map(|defaults| defaults.clone().with_message(message))
-                .unwrap_or_else(|| ToastBuilder::new(message))
-                .with_level(ToastLevel::Info),
-        );
-        // ToastBuilder::new(message).with_level(ToastLevel::Info));
+    pub fn info(&self, message: &str) -> ToastHandle {
+        self.toast(self.builder(message).with_level(ToastLevel::Info))
     }
 
     /// Quickly display a `success` toast with default parameters. For more customization,
@@ -101,14 +140,8 @@ impl ToasterContext {
     ///     toaster.success("My toast message.");
     /// }
     /// ```
-    pub fn success(&self, message: &str) {
-        self.toast(
-            self.defaults
-                .as_ref()
-                .map(|defaults| defaults.clone().with_message(message))
-                .unwrap_or_else(|| ToastBuilder::new(message))
-                .with_level(ToastLevel::Success),
-        );
+    pub fn success(&self, message: &str) -> ToastHandle {
+        self.toast(self.builder(message).with_level(ToastLevel::Success))
     }
 
     /// Quickly display a `warn` toast with default parameters. For more customization,
@@ -122,14 +155,8 @@ impl ToasterContext {
     ///     toaster.warn("My toast message.");
     /// }
     /// ```
-    pub fn warn(&self, message: &str) {
-        self.toast(
-            self.defaults
-                .as_ref()
-                .map(|defaults| defaults.clone().with_message(message))
-                .unwrap_or_else(|| ToastBuilder::new(message))
-                .with_level(ToastLevel::Warn),
-        );
+    pub fn warn(&self, message: &str) -> ToastHandle {
+        self.toast(self.builder(message).with_level(ToastLevel::Warn))
     }
 
     /// Quickly display an `error` toast with default parameters. For more customization,
@@ -143,14 +170,62 @@ impl ToasterContext {
     ///     toaster.error("My toast message.");
     /// }
     /// ```
-    pub fn error(&self, message: &str) {
-        self.toast(
-            self.defaults
-                .as_ref()
-                .map(|defaults| defaults.clone().with_message(message))
-                .unwrap_or_else(|| ToastBuilder::new(message))
-                .with_level(ToastLevel::Error),
+    pub fn error(&self, message: &str) -> ToastHandle {
+        self.toast(self.builder(message).with_level(ToastLevel::Error))
+    }
+
+    /// Displays a non-expiring loading toast while the supplied future is pending,
+    /// then updates it into a `success` or `error` toast with default parameters
+    /// once the future resolves.
+    ///
+    /// # Examples
+    /// ```
+    /// #[leptos::component]
+    /// fn Component() -> impl leptos::IntoView {
+    ///     let toaster = leptoaster::expect_toaster();
+    ///
+    ///     toaster.promise(
+    ///         async { Ok::<u32, String>(3) },
+    ///         leptoaster::PromiseMessages::new(
+    ///             "Uploading files...",
+    ///             |count| format!("Uploaded {count} files."),
+    ///             |error| format!("Upload failed: {error}"),
+    ///         ),
+    ///     );
+    /// }
+    /// ```
+    pub fn promise<T, E>(
+        &self,
+        future: impl Future<Output = Result<T, E>> + 'static,
+        messages: PromiseMessages<T, E>,
+    ) -> ToastHandle
+    where
+        T: 'static,
+        E: 'static,
+    {
+        let handle = self.toast(
+            self.builder(&messages.loading)
+                .with_expiry(None)
+                .with_loading(true),
         );
+
+        let toaster = self.clone();
+
+        spawn_local(async move {
+            let builder = match future.await {
+                Ok(value) => toaster
+                    .builder(&(messages.success)(&value))
+                    .with_level(ToastLevel::Success),
+
+                Err(error) => toaster
+                    .builder(&(messages.error)(&error))
+                    .with_level(ToastLevel::Error),
+            };
+
+            toaster.update(handle.id(), builder);
+        });
+
+        handle
     }
 
     /// Clears all currently visible toasts.
@@ -170,37 +245,393 @@ impl ToasterContext {
     /// }
     /// ```
     pub fn clear(&self) {
+        for toast in &self.pending.get_untracked() {
+            ToastHandle::new(toast, self.pending).close(CloseReason::Cleared);
+        }
+
         for toast in &self.queue.get_untracked() {
-            toast.clear_signal.set(true);
+            ToastHandle::new(toast, self.pending).close(CloseReason::Cleared);
         }
     }
 
-    /// Removes the toast corresponding with the supplied `ToastId`.
+    /// Updates the message, title, description, level, expiry, and progress bar of the
+    /// toast corresponding with the supplied `ToastId` in place, restarting its expiry
+    /// timer. Does nothing if the toast is no longer in the queue or the pending queue.
+    ///
+    /// # Examples
+    /// ```
+    /// #[leptos::component]
+    /// fn Component() -> impl leptos::IntoView {
+    ///     let toaster = leptoaster::expect_toaster();
+    ///
+    ///     let handle = toaster.toast(
+    ///         leptoaster::ToastBuilder::new("Uploading...")
+    ///             .with_expiry(None)
+    ///     );
+    ///
+    ///     toaster.update(
+    ///         handle.id(),
+    ///         leptoaster::ToastBuilder::new("Uploaded.")
+    ///             .with_level(leptoaster::ToastLevel::Success)
+    ///     );
+    /// }
+    /// ```
+    pub fn update(&self, toast_id: ToastId, builder: ToastBuilder) {
+        let find =
+            |toasts: &Vec<ToastData>| toasts.iter().find(|toast| toast.id == toast_id).cloned();
+
+        // a named toaster is disposed along with the component which provided it, which
+        // may be unmounted before a promise updating one of its toasts resolves
+        let toast = self
+            .queue
+            .try_with_untracked(find)
+            .flatten()
+            .or_else(|| self.pending.try_with_untracked(find).flatten());
+
+        if let Some(toast) = toast {
+            builder.apply(&toast);
+        }
+    }
+
+    /// Removes the toast corresponding with the supplied `ToastId` immediately,
+    /// without playing its slide-out animation, and displays the next pending
+    /// toast in its position.
     pub fn remove(&self, toast_id: ToastId) {
-        let index = self
+        let Some(index) = self
             .queue
+            .try_with_untracked(|queue| queue.iter().position(|toast| toast.id == toast_id))
+        else {
+            // the toaster has already been disposed
+            return;
+        };
+
+        let Some(index) = index else {
+            self.withdraw(toast_id, CloseReason::Removed);
+            return;
+        };
+
+        let mut queue = self.queue.get_untracked();
+        let toast = queue.remove(index);
+        self.queue.set(queue);
+
+        self.stats.update_value(|stats| stats.visible -= 1);
+        self.promote();
+
+        // toasts which were already being dismissed have notified their
+        // `on_close` callback by the time they are removed
+        if !toast.clear_signal.get_untracked() {
+            toast.clear_signal.set(true);
+
+            if let Some(on_close) = &toast.on_close {
+                on_close.call(CloseReason::Removed);
+            }
+        }
+    }
+}
+
+impl ToasterContext {
+    /// Sets the maximum number of toasts visible in each position, displaying
+    /// any pending toasts which now fit on the screen.
+    pub(crate) fn set_max_visible(&self, max_visible: Option<usize>) {
+        self.max_visible.set_value(max_visible);
+        self.promote();
+    }
+
+    /// Returns snapshots of the visible and pending toasts which have not been dismissed,
+    /// in the order in which they were added.
+    pub(crate) fn snapshot(&self) -> Vec<ToastSnapshot> {
+        self.queue
             .get_untracked()
             .iter()
-            .enumerate()
-            .find(|(_, toast)| toast.id == toast_id)
-            .map(|(index, _)| index);
+            .chain(self.pending.get_untracked().iter())
+            .filter(|toast| !toast.clear_signal.get_untracked())
+            .map(ToastSnapshot::new)
+            .collect()
+    }
+
+    /// Adds the toasts of the supplied snapshots, skipping any which the toaster already
+    /// holds. Restored toasts keep their IDs, so toasts added afterwards are numbered
+    /// after them.
+    pub(crate) fn restore(&self, snapshots: Vec<ToastSnapshot>) {
+        for snapshot in snapshots {
+            let exists =
+                |toasts: &Vec<ToastData>| toasts.iter().any(|toast| toast.id == snapshot.id);
+
+            if self.queue.with_untracked(exists) || self.pending.with_untracked(exists) {
+                continue;
+            }
+
+            self.stats
+                .update_value(|stats| stats.total = stats.total.max(snapshot.id));
+
+            let toast = snapshot.into_data();
+
+            if self.is_position_full(&toast.position) {
+                self.pending.update(|pending| pending.push(toast));
+            } else {
+                self.show(toast);
+            }
+        }
+    }
+
+    /// Closes the toast corresponding with the supplied `ToastId` if it is waiting
+    /// in the pending queue, which withdraws it from the queue.
+    fn withdraw(&self, toast_id: ToastId, reason: CloseReason) {
+        let toast = self
+            .pending
+            .with_untracked(|pending| pending.iter().find(|toast| toast.id == toast_id).cloned());
+
+        if let Some(toast) = toast {
+            ToastHandle::new(&toast, self.pending).close(reason);
+        }
+    }
+
+    /// Moves focus into the first toast, remembering the previously focused element.
+    pub(crate) fn focus_toasts(&self) {
+        let selector = format!(
+            "{} [data-leptoaster-toast]",
+            get_toaster_selector(self.name())
+        );
 
-        if let Some(index) = index {
-            let mut queue = self.queue.get_untracked();
-            queue.remove(index);
-            self.queue.set(queue);
+        let Ok(Some(toast)) = document().query_selector(&selector) else {
+            return;
+        };
 
-            self.stats.borrow_mut().visible -= 1;
+        let active = document().active_element();
+
+        // focus moved over from another toaster's toasts is returned to them
+        let is_toast_focused = active
+            .as_ref()
+            .is_some_and(|active| active.closest(&selector).is_ok_and(|toast| toast.is_some()));
+
+        if !is_toast_focused {
+            self.previous_focus.set_value(active);
         }
+
+        focus_element(toast);
+    }
+
+    /// Returns focus to the element which was focused before the toasts were.
+    pub(crate) fn restore_focus(&self) {
+        if let Some(element) = self.previous_focus.get_value() {
+            self.previous_focus.set_value(None);
+            focus_element(element);
+        }
+    }
+
+    fn show(&self, toast: ToastData) {
+        self.queue.update(|queue| queue.push(toast));
+        self.stats.update_value(|stats| stats.visible += 1);
+    }
+
+    /// Moves pending toasts into the toast queue, in the order in which they were
+    /// added, for as long as their positions have room for them.
+    fn promote(&self) {
+        let pending = self.pending.get_untracked();
+        let mut still_pending = Vec::new();
+
+        for toast in pending {
+            if self.is_position_full(&toast.position) {
+                still_pending.push(toast);
+            } else {
+                self.show(toast);
+            }
+        }
+
+        self.pending.set(still_pending);
+    }
+
+    fn find_duplicate(&self, dedupe_key: &str) -> Option<ToastData> {
+        self.queue
+            .get_untracked()
+            .into_iter()
+            .chain(self.pending.get_untracked())
+            .find(|toast| {
+                toast.dedupe_key.as_deref() == Some(dedupe_key)
+                    && !toast.clear_signal.get_untracked()
+            })
+    }
+
+    fn is_position_full(&self, position: &ToastPosition) -> bool {
+        let Some(max_visible) = self.max_visible.get_value() else {
+            return false;
+        };
+
+        let visible = self.queue.with_untracked(|queue| {
+            queue
+                .iter()
+                .filter(|toast| toast.position.eq(position))
+                .count()
+        });
+
+        visible >= max_visible
+    }
+
+    fn builder(&self, message: &str) -> ToastBuilder {
+        self.defaults
+            .as_ref()
+            .map(|defaults| defaults.clone().with_message(message))
+            .unwrap_or_else(|| ToastBuilder::new(message))
     }
 }
 
+/// Returns the CSS selector of the containers of the toaster with the supplied name.
+pub(crate) fn get_toaster_selector(name: Option<&str>) -> String {
+    let name = name
+        .unwrap_or_default()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    format!("[data-leptoaster-toaster=\"{name}\"]")
+}
+
+pub(crate) fn focus_element(element: web_sys::Element) {
+    _ = element.unchecked_into::<web_sys::HtmlElement>().focus();
+}
+
 impl Default for ToasterContext {
     fn default() -> Self {
-        ToasterContext {
-            stats: Rc::new(RefCell::new(ToasterStats::default())),
-            queue: create_rw_signal(Vec::new()),
-            defaults: None,
-        }
+        ToasterContext::new(None, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    fn ids(toasts: RwSignal<Vec<ToastData>>) -> Vec<ToastId> {
+        toasts.with_untracked(|toasts| toasts.iter().map(|toast| toast.id).collect())
+    }
+
+    #[test]
+    fn disposed_toaster_ignores_updates_and_removals() {
+        let runtime = create_runtime();
+
+        let (toaster, disposer) = as_child_of_current_owner(|()| ToasterContext::default())(());
+        let handle = toaster.info("Saving the draft...");
+
+        drop(disposer);
+
+        toaster.update(handle.id(), ToastBuilder::new("Saved the draft."));
+        toaster.remove(handle.id());
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn pending_toast_is_not_visible_until_displayed() {
+        let runtime = create_runtime();
+
+        let toaster = ToasterContext::default();
+        toaster.set_max_visible(Some(1));
+
+        let first = toaster.info("Saved the draft.");
+        let second = toaster.info("Published the post.");
+
+        assert!(first.is_visible().get_untracked());
+        assert!(!second.is_visible().get_untracked());
+
+        toaster.remove(first.id());
+
+        assert!(!first.is_visible().get_untracked());
+        assert!(second.is_visible().get_untracked());
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn toasts_over_the_limit_of_their_position_wait_in_the_pending_queue() {
+        let runtime = create_runtime();
+
+        let toaster = ToasterContext::default();
+        toaster.set_max_visible(Some(2));
+
+        toaster.info("First");
+        toaster.info("Second");
+        toaster.info("Third");
+        toaster.toast(ToastBuilder::new("Fourth").with_position(ToastPosition::TopLeft));
+
+        assert_eq!(ids(toaster.queue), vec![1, 2, 4]);
+        assert_eq!(ids(toaster.pending), vec![3]);
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn removing_a_toast_promotes_the_next_pending_toast_in_its_position() {
+        let runtime = create_runtime();
+
+        let toaster = ToasterContext::default();
+        toaster.set_max_visible(Some(1));
+
+        let first = toaster.info("First");
+        toaster.toast(ToastBuilder::new("Second").with_position(ToastPosition::TopLeft));
+        toaster.toast(ToastBuilder::new("Third").with_position(ToastPosition::TopLeft));
+        toaster.info("Fourth");
+
+        assert_eq!(ids(toaster.pending), vec![3, 4]);
+
+        toaster.remove(first.id());
+
+        assert_eq!(ids(toaster.queue), vec![2, 4]);
+        assert_eq!(ids(toaster.pending), vec![3]);
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn clearing_withdraws_pending_toasts() {
+        let runtime = create_runtime();
+
+        let toaster = ToasterContext::default();
+        toaster.set_max_visible(Some(1));
+
+        let reason = Rc::new(Cell::new(None));
+
+        let visible = toaster.info("Visible");
+        let pending = toaster.toast(ToastBuilder::new("Pending").on_close({
+            let reason = Rc::clone(&reason);
+            move |closed| reason.set(Some(closed))
+        }));
+
+        toaster.clear();
+
+        assert!(ids(toaster.pending).is_empty());
+        assert_eq!(reason.get(), Some(CloseReason::Cleared));
+        assert!(!pending.is_visible().get_untracked());
+
+        // visible toasts animate out before they are removed
+        assert_eq!(ids(toaster.queue), vec![visible.id()]);
+        assert!(!visible.is_visible().get_untracked());
+
+        runtime.dispose();
+    }
+
+    #[test]
+    fn duplicate_toast_increments_the_counter_of_the_existing_toast() {
+        let runtime = create_runtime();
+
+        let toaster = ToasterContext::default();
+        let builder = ToastBuilder::new("Could not reach the server.").with_dedupe_key("network");
+
+        let first = toaster.toast(builder.clone());
+        let second = toaster.toast(builder);
+
+        assert_eq!(first.id(), second.id());
+        assert_eq!(ids(toaster.queue), vec![first.id()]);
+
+        let toast = toaster.queue.with_untracked(|queue| queue[0].clone());
+        assert_eq!(toast.count.get_untracked(), 2);
+        assert_eq!(toast.restart.get_untracked(), 1);
+
+        // once the toast is dismissed, the same key adds a new toast
+        first.dismiss();
+        let third = toaster
+            .toast(ToastBuilder::new("Could not reach the server.").with_dedupe_key("network"));
+
+        assert_ne!(third.id(), first.id());
+
+        runtime.dispose();
     }
 }
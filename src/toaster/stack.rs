@@ -0,0 +1,237 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashMap;
+
+use crate::toast::ToastId;
+
+/// The step by which the width of each toast behind the front of a stack is scaled down.
+const STACK_SCALE_STEP: f64 = 0.02;
+
+/// The measured height of a toast, and the spacing between it and its neighbours.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub(crate) struct ToastSize {
+    pub height: f64,
+    pub spacing: f64,
+}
+
+/// The layout of a toast within a stack.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct ToastStack {
+    pub translate: f64,
+    pub scale: f64,
+    pub height: Option<f64>,
+    pub z_index: usize,
+    pub hidden: bool,
+    pub expanded: bool,
+}
+
+/// The settings shared by every toast in a stack.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct StackLayout {
+    pub depth: usize,
+    pub gap: f64,
+    pub bottom: bool,
+    pub expanded: bool,
+    pub reduced_motion: bool,
+}
+
+/// Returns the layout of the toast at the supplied index of a stack, where the toast at
+/// index `0` is at the front. Collapsed toasts take the height of the front toast so that
+/// each peeks out from behind the one in front of it by the stack's gap. Expanded toasts
+/// keep their measured heights and are laid out one after another.
+pub(crate) fn get_toast_stack(
+    index: usize,
+    ids: &[ToastId],
+    sizes: &HashMap<ToastId, ToastSize>,
+    layout: StackLayout,
+) -> ToastStack {
+    // a toast which has just been added is measured once it is rendered, so until then
+    // the stack is laid out with the next toast's size
+    let front = ids
+        .iter()
+        .find_map(|id| sizes.get(id))
+        .copied()
+        .unwrap_or_default();
+
+    let collapsed = index > 0 && !layout.expanded && !layout.reduced_motion && front.height > 0.0;
+
+    let translate = match (collapsed, layout.bottom) {
+        (false, _) => 0.0,
+        (true, true) => index as f64 * (front.height + front.spacing - layout.gap),
+        (true, false) => -(index as f64 * (front.height + front.spacing - layout.gap)),
+    };
+
+    ToastStack {
+        translate,
+        scale: match collapsed {
+            true => 1.0 - index as f64 * STACK_SCALE_STEP,
+            false => 1.0,
+        },
+        height: collapsed.then_some(front.height),
+        z_index: ids.len() - index,
+        hidden: !layout.expanded && index >= layout.depth,
+        expanded: layout.expanded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUT: StackLayout = StackLayout {
+        depth: 3,
+        gap: 8.0,
+        bottom: true,
+        expanded: false,
+        reduced_motion: false,
+    };
+
+    fn sizes(ids: &[ToastId], height: f64) -> HashMap<ToastId, ToastSize> {
+        ids.iter()
+            .map(|id| {
+                (
+                    *id,
+                    ToastSize {
+                        height,
+                        spacing: 12.0,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn front_toast_is_not_collapsed() {
+        let ids = [3, 2, 1];
+        let stack = get_toast_stack(0, &ids, &sizes(&ids, 50.0), LAYOUT);
+
+        assert_eq!(stack.translate, 0.0);
+        assert_eq!(stack.scale, 1.0);
+        assert_eq!(stack.height, None);
+        assert_eq!(stack.z_index, 3);
+        assert!(!stack.hidden);
+    }
+
+    #[test]
+    fn collapsed_toasts_peek_out_by_the_gap() {
+        let ids = [3, 2, 1];
+        let sizes = sizes(&ids, 50.0);
+
+        let second = get_toast_stack(1, &ids, &sizes, LAYOUT);
+        let third = get_toast_stack(2, &ids, &sizes, LAYOUT);
+
+        assert_eq!(second.translate, 54.0);
+        assert_eq!(second.scale, 0.98);
+        assert_eq!(second.height, Some(50.0));
+        assert_eq!(second.z_index, 2);
+
+        assert_eq!(third.translate, 108.0);
+        assert_eq!(third.scale, 0.96);
+        assert_eq!(third.z_index, 1);
+    }
+
+    #[test]
+    fn collapsed_toasts_take_the_height_of_the_front_toast() {
+        let ids = [2, 1];
+        let mut sizes = sizes(&ids, 50.0);
+        sizes.insert(
+            1,
+            ToastSize {
+                height: 90.0,
+                spacing: 12.0,
+            },
+        );
+
+        let stack = get_toast_stack(1, &ids, &sizes, LAYOUT);
+
+        assert_eq!(stack.translate, 54.0);
+        assert_eq!(stack.height, Some(50.0));
+    }
+
+    #[test]
+    fn top_stacks_are_collapsed_upwards() {
+        let ids = [3, 2, 1];
+        let layout = StackLayout {
+            bottom: false,
+            ..LAYOUT
+        };
+
+        let stack = get_toast_stack(2, &ids, &sizes(&ids, 50.0), layout);
+
+        assert_eq!(stack.translate, -108.0);
+        assert_eq!(stack.scale, 0.96);
+    }
+
+    #[test]
+    fn expanded_toasts_are_not_collapsed() {
+        let ids = [3, 2, 1];
+        let layout = StackLayout {
+            expanded: true,
+            ..LAYOUT
+        };
+
+        let stack = get_toast_stack(2, &ids, &sizes(&ids, 50.0), layout);
+
+        assert_eq!(stack.translate, 0.0);
+        assert_eq!(stack.scale, 1.0);
+        assert_eq!(stack.height, None);
+        assert!(stack.expanded);
+    }
+
+    #[test]
+    fn toasts_beyond_the_depth_are_hidden_while_collapsed() {
+        let ids = [4, 3, 2, 1];
+        let sizes = sizes(&ids, 50.0);
+
+        assert!(!get_toast_stack(2, &ids, &sizes, LAYOUT).hidden);
+        assert!(get_toast_stack(3, &ids, &sizes, LAYOUT).hidden);
+
+        let layout = StackLayout {
+            expanded: true,
+            ..LAYOUT
+        };
+        assert!(!get_toast_stack(3, &ids, &sizes, layout).hidden);
+    }
+
+    #[test]
+    fn unmeasured_front_toast_takes_the_next_toast_size() {
+        let ids = [3, 2, 1];
+        let sizes = sizes(&ids[1..], 50.0);
+
+        let stack = get_toast_stack(1, &ids, &sizes, LAYOUT);
+
+        assert_eq!(stack.translate, 54.0);
+        assert_eq!(stack.height, Some(50.0));
+    }
+
+    #[test]
+    fn unmeasured_stack_is_not_collapsed() {
+        let ids = [2, 1];
+        let stack = get_toast_stack(1, &ids, &HashMap::new(), LAYOUT);
+
+        assert_eq!(stack.translate, 0.0);
+        assert_eq!(stack.scale, 1.0);
+        assert_eq!(stack.height, None);
+    }
+
+    #[test]
+    fn reduced_motion_stacks_are_not_collapsed() {
+        let ids = [3, 2, 1];
+        let layout = StackLayout {
+            reduced_motion: true,
+            ..LAYOUT
+        };
+
+        let stack = get_toast_stack(1, &ids, &sizes(&ids, 50.0), layout);
+
+        assert_eq!(stack.translate, 0.0);
+        assert_eq!(stack.scale, 1.0);
+        assert_eq!(stack.height, None);
+        assert!(!stack.hidden);
+    }
+}
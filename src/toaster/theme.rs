@@ -0,0 +1,213 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::toast::ToastLevel;
+
+/// The colors of toasts of a single level.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ToastColors {
+    pub background: String,
+    pub border: String,
+    pub text: String,
+    pub icon: String,
+}
+
+impl ToastColors {
+    /// Constructs the colors of a toast level.
+    ///
+    /// # Examples
+    /// ```
+    /// let colors = leptoaster::ToastColors::new("#ffffff", "#222222", "#222222", "#222222");
+    /// ```
+    #[must_use]
+    pub fn new(background: &str, border: &str, text: &str, icon: &str) -> Self {
+        ToastColors {
+            background: background.into(),
+            border: border.into(),
+            text: text.into(),
+            icon: icon.into(),
+        }
+    }
+}
+
+/// A color theme for the toaster, which sets the `--leptoaster-*` color variables of
+/// each toast level. A theme can carry a dark variant, which is used instead while the
+/// user prefers a dark color scheme.
+///
+/// The built-in themes are:
+/// * `light`: white info toasts and colored toasts of other levels (the default)
+/// * `dark`: dark toasts with colored borders and icons
+/// * `rich`: colored toasts of every level
+/// * `minimal`: white toasts with colored icons
+/// * `auto`: the light theme, or the dark theme under `prefers-color-scheme: dark`
+///
+/// # Examples
+/// ```
+/// use leptoaster::{ToastColors, ToasterTheme};
+///
+/// let theme = ToasterTheme::new(
+///     ToastColors::new("#eff6ff", "#3b82f6", "#1e3a8a", "#3b82f6"),
+///     ToastColors::new("#f0fdf4", "#22c55e", "#14532d", "#22c55e"),
+///     ToastColors::new("#fffbeb", "#f59e0b", "#78350f", "#f59e0b"),
+///     ToastColors::new("#fef2f2", "#ef4444", "#7f1d1d", "#ef4444"),
+/// )
+/// .with_dark(ToasterTheme::dark());
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ToasterTheme {
+    pub info: ToastColors,
+    pub success: ToastColors,
+    pub warn: ToastColors,
+    pub error: ToastColors,
+    pub dark: Option<Box<ToasterTheme>>,
+}
+
+impl ToasterTheme {
+    /// Constructs a theme with the supplied colors for each toast level.
+    #[must_use]
+    pub fn new(
+        info: ToastColors,
+        success: ToastColors,
+        warn: ToastColors,
+        error: ToastColors,
+    ) -> Self {
+        ToasterTheme {
+            info,
+            success,
+            warn,
+            error,
+            dark: None,
+        }
+    }
+
+    #[must_use]
+    pub fn light() -> Self {
+        ToasterTheme::new(
+            ToastColors::new("#ffffff", "#222222", "#222222", "#222222"),
+            ToastColors::new("#4caf50", "#2e7d32", "#ffffff", "#ffffff"),
+            ToastColors::new("#ff9800", "#ff8f00", "#ffffff", "#ffffff"),
+            ToastColors::new("#f44336", "#c62828", "#ffffff", "#ffffff"),
+        )
+    }
+
+    #[must_use]
+    pub fn dark() -> Self {
+        ToasterTheme::new(
+            ToastColors::new("#1f1f1f", "#3a3a3a", "#f5f5f5", "#f5f5f5"),
+            ToastColors::new("#1f1f1f", "#2e7d32", "#f5f5f5", "#66bb6a"),
+            ToastColors::new("#1f1f1f", "#ff8f00", "#f5f5f5", "#ffa726"),
+            ToastColors::new("#1f1f1f", "#c62828", "#f5f5f5", "#ef5350"),
+        )
+    }
+
+    #[must_use]
+    pub fn rich() -> Self {
+        ToasterTheme::new(
+            ToastColors::new("#2196f3", "#1565c0", "#ffffff", "#ffffff"),
+            ToastColors::new("#43a047", "#1b5e20", "#ffffff", "#ffffff"),
+            ToastColors::new("#fb8c00", "#e65100", "#ffffff", "#ffffff"),
+            ToastColors::new("#e53935", "#b71c1c", "#ffffff", "#ffffff"),
+        )
+    }
+
+    #[must_use]
+    pub fn minimal() -> Self {
+        ToasterTheme::new(
+            ToastColors::new("#ffffff", "#e0e0e0", "#222222", "#222222"),
+            ToastColors::new("#ffffff", "#e0e0e0", "#222222", "#2e7d32"),
+            ToastColors::new("#ffffff", "#e0e0e0", "#222222", "#ef6c00"),
+            ToastColors::new("#ffffff", "#e0e0e0", "#222222", "#c62828"),
+        )
+    }
+
+    #[must_use]
+    pub fn auto() -> Self {
+        ToasterTheme::light().with_dark(ToasterTheme::dark())
+    }
+
+    /// Sets the theme which is used while the user prefers a dark color scheme.
+    ///
+    /// # Examples
+    /// ```
+    /// use leptoaster::ToasterTheme;
+    ///
+    /// let theme = ToasterTheme::minimal().with_dark(ToasterTheme::dark());
+    /// ```
+    #[must_use]
+    pub fn with_dark(mut self, dark: ToasterTheme) -> Self {
+        self.dark = Some(Box::new(dark));
+        self
+    }
+
+    #[must_use]
+    pub fn for_level(&self, level: &ToastLevel) -> &ToastColors {
+        match level {
+            ToastLevel::Info => &self.info,
+            ToastLevel::Success => &self.success,
+            ToastLevel::Warn => &self.warn,
+            ToastLevel::Error => &self.error,
+        }
+    }
+
+    /// Returns the CSS which sets the theme's variables on the elements matching the
+    /// supplied selector, so that each toaster can be given its own theme.
+    pub(crate) fn to_css(&self, selector: &str) -> String {
+        let mut css = format!("{selector} {{ {} }}", self.variables());
+
+        if let Some(dark) = &self.dark {
+            css.push_str(&format!(
+                " @media (prefers-color-scheme: dark) {{ {selector} {{ {} }} }}",
+                dark.variables()
+            ));
+        }
+
+        css
+    }
+
+    fn variables(&self) -> String {
+        [
+            ("info", &self.info),
+            ("success", &self.success),
+            ("warn", &self.warn),
+            ("error", &self.error),
+        ]
+        .into_iter()
+        .map(|(level, colors)| {
+            format!(
+                "--leptoaster-{level}-background-color: {}; \
+                 --leptoaster-{level}-border-color: {}; \
+                 --leptoaster-{level}-text-color: {}; \
+                 --leptoaster-{level}-icon-color: {};",
+                colors.background, colors.border, colors.text, colors.icon,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+    }
+}
+
+impl Default for ToasterTheme {
+    fn default() -> Self {
+        ToasterTheme::light()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn css_is_scoped_to_the_selector() {
+        let css = ToasterTheme::auto().to_css("[data-leptoaster-toaster=\"editor\"]");
+
+        assert!(css.starts_with("[data-leptoaster-toaster=\"editor\"] { --leptoaster-info-"));
+        assert!(css.contains(
+            "@media (prefers-color-scheme: dark) { [data-leptoaster-toaster=\"editor\"] {"
+        ));
+        assert!(!css.contains(":root"));
+    }
+}